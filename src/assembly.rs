@@ -0,0 +1,182 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::records::Record;
+
+//------------------------------------- ASSEMBLY -------------------------------------//
+
+const EXACT_LIMIT: usize = 16;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Placement {
+    pub id: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Assembly<M: Monomer> {
+    pub contig: Polymer<M>,
+    pub placements: Vec<Placement>,
+}
+
+fn overlap<M: Monomer>(source: &[M], target: &[M]) -> usize {
+    let longest = source.len().min(target.len());
+    (1..longest)
+        .rev()
+        .find(|k| source[source.len() - k..] == target[..*k])
+        .unwrap_or(0)
+}
+
+fn contained<M: Monomer>(records: &[Record<M>]) -> Vec<bool> {
+    let reads = records
+        .iter()
+        .map(|r| &r.polymer.monomers)
+        .collect::<Vec<&Vec<M>>>();
+    (0..reads.len())
+        .map(|j| {
+            (0..reads.len()).any(|i| {
+                i != j
+                    && reads[i].len() >= reads[j].len()
+                    && (reads[i] != reads[j] || i < j)
+                    && (reads[j].is_empty()
+                        || reads[i].windows(reads[j].len()).any(|w| w == &reads[j][..]))
+            })
+        })
+        .collect()
+}
+
+impl<M: Monomer> Assembly<M> {
+    fn from_order(records: &[Record<M>], order: &[usize], overlaps: &[Vec<usize>]) -> Self {
+        let mut monomers = Vec::new();
+        let mut starts = vec![None; records.len()];
+        let mut previous: Option<usize> = None;
+        for read in order {
+            let shared = previous.map_or(0, |p| overlaps[p][*read]);
+            starts[*read] = Some(monomers.len() - shared);
+            monomers.extend_from_slice(&records[*read].polymer.monomers[shared..]);
+            previous = Some(*read);
+        }
+        let placements = records
+            .iter()
+            .zip(starts)
+            .map(|(record, start)| {
+                let read = &record.polymer.monomers;
+                let start = start.unwrap_or_else(|| {
+                    monomers
+                        .windows(read.len().max(1))
+                        .position(|w| read.is_empty() || w == &read[..])
+                        .unwrap_or(0)
+                });
+                Placement {
+                    id: record.id.clone(),
+                    start: start + 1,
+                    end: start + read.len(),
+                }
+            })
+            .collect();
+        Assembly {
+            contig: Polymer { monomers },
+            placements,
+        }
+    }
+    fn overlaps(records: &[Record<M>], reads: &[usize]) -> Vec<Vec<usize>> {
+        let mut overlaps = vec![vec![0; records.len()]; records.len()];
+        for i in reads {
+            for j in reads.iter().filter(|j| *j != i) {
+                overlaps[*i][*j] =
+                    overlap(&records[*i].polymer.monomers, &records[*j].polymer.monomers);
+            }
+        }
+        overlaps
+    }
+    pub fn greedy(records: &[Record<M>]) -> Option<Self> {
+        if records.is_empty() {
+            return None;
+        }
+        let contained = contained(records);
+        let reads = (0..records.len())
+            .filter(|i| !contained[*i])
+            .collect::<Vec<usize>>();
+        let overlaps = Assembly::overlaps(records, &reads);
+        let mut pairs = reads
+            .iter()
+            .flat_map(|i| reads.iter().filter(move |j| *j != i).map(move |j| (*i, *j)))
+            .filter(|(i, j)| overlaps[*i][*j] > 0)
+            .collect::<Vec<(usize, usize)>>();
+        pairs.sort_by_key(|(i, j)| (usize::MAX - overlaps[*i][*j], *i, *j));
+        let mut next = vec![None; records.len()];
+        let mut previous = vec![None; records.len()];
+        for (i, j) in pairs {
+            if next[i].is_some() || previous[j].is_some() {
+                continue;
+            }
+            let mut head = i;
+            while let Some(p) = previous[head] {
+                head = p;
+            }
+            if head == j {
+                continue;
+            }
+            next[i] = Some(j);
+            previous[j] = Some(i);
+        }
+        let mut order = Vec::with_capacity(reads.len());
+        for read in reads.iter().filter(|r| previous[**r].is_none()) {
+            let mut current = Some(*read);
+            while let Some(c) = current {
+                order.push(c);
+                current = next[c];
+            }
+        }
+        Some(Assembly::from_order(records, &order, &overlaps))
+    }
+    pub fn exact(records: &[Record<M>]) -> Option<Self> {
+        if records.is_empty() {
+            return None;
+        }
+        let contained = contained(records);
+        let reads = (0..records.len())
+            .filter(|i| !contained[*i])
+            .collect::<Vec<usize>>();
+        if reads.len() > EXACT_LIMIT {
+            return None;
+        }
+        let overlaps = Assembly::overlaps(records, &reads);
+        let n = reads.len();
+        let full = (1usize << n) - 1;
+        let mut best = vec![vec![None; n]; full + 1];
+        let mut from = vec![vec![usize::MAX; n]; full + 1];
+        for last in 0..n {
+            best[1 << last][last] = Some(0);
+        }
+        for set in 1..=full {
+            for last in 0..n {
+                let Some(score) = best[set][last] else {
+                    continue;
+                };
+                for extension in 0..n {
+                    if set & (1 << extension) != 0 {
+                        continue;
+                    }
+                    let extended = set | (1 << extension);
+                    let candidate = score + overlaps[reads[last]][reads[extension]];
+                    if best[extended][extension].is_none_or(|b| candidate > b) {
+                        best[extended][extension] = Some(candidate);
+                        from[extended][extension] = last;
+                    }
+                }
+            }
+        }
+        let mut last = (0..n).max_by_key(|l| (best[full][*l], usize::MAX - l))?;
+        let mut set = full;
+        let mut order = Vec::with_capacity(n);
+        while set != 0 {
+            order.push(reads[last]);
+            let previous = from[set][last];
+            set &= !(1 << last);
+            last = previous;
+        }
+        order.reverse();
+        Some(Assembly::from_order(records, &order, &overlaps))
+    }
+}
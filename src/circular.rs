@@ -0,0 +1,188 @@
+use crate::monomers::Codon;
+use crate::monomers::Monomer;
+use crate::monomers::Nucleotide;
+use crate::monomers::RnaNucleotide;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use crate::polymers::Protein;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+//-------------------------------- CIRCULAR POLYMER ----------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd, Debug)]
+pub struct CircularPolymer<M: Monomer> {
+    polymer: Polymer<M>,
+}
+
+impl<M: Monomer> From<Polymer<M>> for CircularPolymer<M> {
+    fn from(polymer: Polymer<M>) -> Self {
+        CircularPolymer { polymer }
+    }
+}
+
+impl<M: Monomer> CircularPolymer<M> {
+    pub fn new(symbols: &str, monomer: fn(char) -> Option<M>) -> Option<Self> {
+        Polymer::new(symbols, monomer).map(CircularPolymer::from)
+    }
+    pub fn from_kmers(kmers: &[Polymer<M>]) -> Option<Self> {
+        let first = kmers.first()?;
+        if kmers.iter().any(|kmer| kmer.monomers.is_empty()) {
+            return None;
+        }
+        let distinct = kmers.iter().collect::<HashSet<&Polymer<M>>>();
+        let mut successors = HashMap::new();
+        for kmer in &distinct {
+            let prefix = &kmer.monomers[..kmer.monomers.len() - 1];
+            if successors.insert(prefix, *kmer).is_some() {
+                return None;
+            }
+        }
+        let mut monomers = Vec::new();
+        let mut current = first;
+        loop {
+            monomers.push(current.monomers[0]);
+            current = successors.get(&current.monomers[1..])?;
+            if current == first {
+                break;
+            }
+            if monomers.len() > distinct.len() {
+                return None;
+            }
+        }
+        if monomers.len() != distinct.len() {
+            return None;
+        }
+        let polymer = Polymer { monomers };
+        Some(CircularPolymer { polymer })
+    }
+    pub fn len(&self) -> usize {
+        self.polymer.monomers.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.polymer.monomers.is_empty()
+    }
+    pub fn as_linear(&self) -> &Polymer<M> {
+        &self.polymer
+    }
+    pub fn linearize(&self, overhang: usize) -> Polymer<M> {
+        let monomers = self
+            .polymer
+            .monomers
+            .iter()
+            .cycle()
+            .take(self.len() + overhang)
+            .copied()
+            .collect();
+        Polymer { monomers }
+    }
+    pub fn rotate(&self, origin: usize) -> CircularPolymer<M> {
+        let mut monomers = self.polymer.monomers.clone();
+        if !monomers.is_empty() {
+            monomers.rotate_left(origin % self.len());
+        }
+        let polymer = Polymer { monomers };
+        CircularPolymer { polymer }
+    }
+    pub fn canonical_rotation(&self) -> CircularPolymer<M> {
+        let monomers = &self.polymer.monomers;
+        let length = monomers.len();
+        let (mut i, mut j, mut k) = (0, 1, 0);
+        while i < length && j < length && k < length {
+            let a = monomers[(i + k) % length];
+            let b = monomers[(j + k) % length];
+            if a == b {
+                k += 1;
+                continue;
+            }
+            if a > b {
+                i += k + 1;
+            } else {
+                j += k + 1;
+            }
+            if i == j {
+                j += 1;
+            }
+            k = 0;
+        }
+        self.rotate(i.min(j))
+    }
+    pub fn is_rotation_of(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.canonical_rotation() == other.canonical_rotation()
+    }
+    pub fn motif_locations(&self, motif: &PolymerSlice<M>) -> Vec<usize> {
+        if motif.is_empty() || self.is_empty() {
+            return Vec::new();
+        }
+        self.linearize(motif.len() - 1)
+            .motif_locations(motif)
+            .into_iter()
+            .filter(|location| *location <= self.len())
+            .collect()
+    }
+    pub fn kmers(&self, k: usize) -> Vec<Polymer<M>> {
+        if k == 0 || self.is_empty() {
+            return Vec::new();
+        }
+        self.linearize(k - 1)
+            .kmers(k)
+            .take(self.len())
+            .map(PolymerSlice::to_polymer)
+            .collect()
+    }
+    pub fn kmer_count(&self, k: usize) -> HashMap<Polymer<M>, usize> {
+        let mut count = HashMap::new();
+        for kmer in self.kmers(k) {
+            *count.entry(kmer).or_insert(0) += 1;
+        }
+        count
+    }
+}
+
+//----------------------------- CIRCULAR NUCLEIC ACID --------------------------------//
+
+impl<N: Nucleotide> CircularPolymer<N> {
+    pub fn gc_content(&self) -> f64 {
+        self.polymer.gc_content()
+    }
+    pub fn reverse_complement(&self) -> CircularPolymer<N> {
+        let polymer = self.polymer.reverse_complement();
+        CircularPolymer { polymer }
+    }
+}
+
+//---------------------------------- CIRCULAR RNA ------------------------------------//
+
+pub type CircularRna = CircularPolymer<RnaNucleotide>;
+
+impl CircularRna {
+    pub fn translate(&self) -> Vec<Protein> {
+        let length = self.len();
+        let monomers = &self.polymer.monomers;
+        let codon = |i: usize| {
+            Codon(
+                monomers[i % length],
+                monomers[(i + 1) % length],
+                monomers[(i + 2) % length],
+            )
+        };
+        let mut translations = Vec::new();
+        for start in 0..length {
+            if codon(start).aminoacid().is_some_and(|a| a.is_start()) {
+                let mut protein = Vec::new();
+                let mut offset = 0;
+                while offset + 3 <= length {
+                    match codon(start + offset).aminoacid() {
+                        Some(aminoacid) => protein.push(aminoacid),
+                        None => {
+                            translations.push(Polymer { monomers: protein });
+                            break;
+                        }
+                    }
+                    offset += 3;
+                }
+            }
+        }
+        translations
+    }
+}
@@ -0,0 +1,97 @@
+use crate::monomers::Nucleotide;
+use crate::records::Record;
+use std::collections::HashMap;
+
+//------------------------------- ERROR CORRECTION -----------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Correction<N: Nucleotide> {
+    pub id: String,
+    pub old: N,
+    pub new: N,
+    pub position: usize,
+}
+
+fn canonical<N: Nucleotide>(kmer: &[N]) -> Vec<N> {
+    let complement = kmer.iter().rev().map(|n| n.complement()).collect();
+    kmer.to_vec().min(complement)
+}
+
+fn spectrum<N: Nucleotide>(records: &[Record<N>], k: usize) -> HashMap<Vec<N>, usize> {
+    let mut spectrum = HashMap::new();
+    for record in records {
+        for kmer in record.polymer.kmers(k) {
+            *spectrum.entry(canonical(&kmer.monomers)).or_insert(0) += 1;
+        }
+    }
+    spectrum
+}
+
+impl<N: Nucleotide> Record<N> {
+    pub fn correct_errors(records: &[Self], k: usize, threshold: usize) -> Vec<Correction<N>> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let spectrum = spectrum(records, k);
+        let solid = |read: &[N]| {
+            read.windows(k)
+                .filter(|kmer| {
+                    spectrum
+                        .get(&canonical(kmer))
+                        .is_some_and(|c| *c >= threshold)
+                })
+                .count()
+        };
+        let mut corrections = Vec::new();
+        for record in records {
+            let mut read = record.polymer.monomers.clone();
+            if read.len() < k {
+                continue;
+            }
+            let mut current = solid(&read);
+            while current < read.len() - k + 1 {
+                let mut candidates = Vec::new();
+                for position in 0..read.len() {
+                    let old = read[position];
+                    for new in N::ALPHABET.iter().filter(|n| **n != old) {
+                        read[position] = *new;
+                        candidates.push((solid(&read), position, *new));
+                    }
+                    read[position] = old;
+                }
+                candidates.sort_unstable_by_key(|c| usize::MAX - c.0);
+                let (score, position, new) = match candidates.as_slice() {
+                    [best] => best,
+                    [best, second, ..] if best.0 > second.0 => best,
+                    _ => break,
+                };
+                if *score <= current {
+                    break;
+                }
+                corrections.push(Correction {
+                    id: record.id.clone(),
+                    old: read[*position],
+                    new: *new,
+                    position: position + 1,
+                });
+                read[*position] = *new;
+                current = *score;
+            }
+        }
+        corrections
+    }
+    pub fn correct_reads(records: &[Self]) -> Vec<Correction<N>> {
+        let k = records.iter().map(|r| r.polymer.len()).min().unwrap_or(0);
+        Record::correct_errors(records, k, 2)
+    }
+    pub fn corrected(&self, corrections: &[Correction<N>]) -> Self {
+        let mut record = self.clone();
+        for correction in corrections.iter().filter(|c| c.id == self.id) {
+            let index = correction.position.checked_sub(1);
+            if let Some(monomer) = index.and_then(|i| record.polymer.monomers.get_mut(i)) {
+                *monomer = correction.new;
+            }
+        }
+        record
+    }
+}
@@ -0,0 +1,220 @@
+use crate::circular::CircularPolymer;
+use crate::monomers::Monomer;
+use crate::monomers::Nucleotide;
+use crate::polymers::Polymer;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+//-------------------------------- DE BRUIJN GRAPH -----------------------------------//
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct DeBruijnGraph<M: Monomer> {
+    nodes: Vec<Polymer<M>>,
+    edges: Vec<Vec<usize>>,
+}
+
+impl<M: Monomer> DeBruijnGraph<M> {
+    fn from_kmers(kmers: BTreeSet<&[M]>) -> Self {
+        let mut index = HashMap::new();
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut node = |monomers: &[M], edges: &mut Vec<Vec<usize>>| {
+            *index.entry(monomers.to_vec()).or_insert_with(|| {
+                nodes.push(Polymer {
+                    monomers: monomers.to_vec(),
+                });
+                edges.push(Vec::new());
+                nodes.len() - 1
+            })
+        };
+        for kmer in kmers {
+            let prefix = node(&kmer[..kmer.len() - 1], &mut edges);
+            let suffix = node(&kmer[1..], &mut edges);
+            edges[prefix].push(suffix);
+        }
+        DeBruijnGraph { nodes, edges }
+    }
+    pub fn new(reads: &[Polymer<M>], k: usize) -> Self {
+        let kmers = reads
+            .iter()
+            .filter(|_| k >= 2)
+            .flat_map(|read| read.kmers(k).map(|kmer| &kmer.monomers))
+            .collect();
+        DeBruijnGraph::from_kmers(kmers)
+    }
+    pub fn nodes(&self) -> &[Polymer<M>] {
+        &self.nodes
+    }
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+    pub fn adjacency_list(&self) -> Vec<(&Polymer<M>, &Polymer<M>)> {
+        let mut adjacency = self
+            .edges
+            .iter()
+            .enumerate()
+            .flat_map(|(i, targets)| targets.iter().map(move |j| (i, *j)))
+            .map(|(i, j)| (&self.nodes[i], &self.nodes[j]))
+            .collect::<Vec<(&Polymer<M>, &Polymer<M>)>>();
+        adjacency.sort();
+        adjacency
+    }
+    pub fn to_edge_list(&self) -> String {
+        self.adjacency_list()
+            .iter()
+            .map(|(prefix, suffix)| format!("({}, {})\n", prefix, suffix))
+            .collect()
+    }
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph debruijn {\n");
+        for (prefix, suffix) in self.adjacency_list() {
+            dot.push_str(&format!("    \"{}\" -> \"{}\";\n", prefix, suffix));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+    fn degrees(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut indegrees = vec![0; self.nodes.len()];
+        for target in self.edges.iter().flatten() {
+            indegrees[*target] += 1;
+        }
+        let outdegrees = self.edges.iter().map(Vec::len).collect();
+        (indegrees, outdegrees)
+    }
+    fn spell(&self, path: &[usize]) -> Polymer<M> {
+        let mut monomers = self.nodes[path[0]].monomers.clone();
+        for node in &path[1..] {
+            monomers.push(*self.nodes[*node].monomers.last().unwrap());
+        }
+        Polymer { monomers }
+    }
+}
+
+impl<N: Nucleotide> DeBruijnGraph<N> {
+    pub fn with_reverse_complements(reads: &[Polymer<N>], k: usize) -> Self {
+        let complements = reads
+            .iter()
+            .map(|r| r.reverse_complement())
+            .collect::<Vec<Polymer<N>>>();
+        let kmers = reads
+            .iter()
+            .chain(complements.iter())
+            .filter(|_| k >= 2)
+            .flat_map(|read| read.kmers(k).map(|kmer| &kmer.monomers))
+            .collect();
+        DeBruijnGraph::from_kmers(kmers)
+    }
+}
+
+//--------------------------------- EULERIAN PATHS -----------------------------------//
+
+impl<M: Monomer> DeBruijnGraph<M> {
+    fn walk(&self, start: usize) -> Option<Vec<usize>> {
+        let mut next = vec![0; self.nodes.len()];
+        let mut stack = vec![start];
+        let mut path = Vec::new();
+        while let Some(&node) = stack.last() {
+            if next[node] < self.edges[node].len() {
+                stack.push(self.edges[node][next[node]]);
+                next[node] += 1;
+            } else {
+                path.push(node);
+                stack.pop();
+            }
+        }
+        path.reverse();
+        if path.len() == self.edge_count() + 1 {
+            Some(path)
+        } else {
+            None
+        }
+    }
+    pub fn eulerian_path(&self) -> Option<Polymer<M>> {
+        let (indegrees, outdegrees) = self.degrees();
+        let mut start = None;
+        let mut ends = 0;
+        for node in 0..self.nodes.len() {
+            match outdegrees[node] as isize - indegrees[node] as isize {
+                0 => {}
+                1 if start.is_none() => start = Some(node),
+                -1 if ends == 0 => ends += 1,
+                _ => return None,
+            }
+        }
+        let start = match start {
+            Some(start) => start,
+            None => (0..self.nodes.len()).find(|n| outdegrees[*n] > 0)?,
+        };
+        self.walk(start).map(|path| self.spell(&path))
+    }
+    pub fn eulerian_cycle(&self) -> Option<CircularPolymer<M>> {
+        let (indegrees, outdegrees) = self.degrees();
+        if indegrees != outdegrees {
+            return None;
+        }
+        let start = (0..self.nodes.len()).find(|n| outdegrees[*n] > 0)?;
+        let path = self.walk(start)?;
+        let monomers = path[..path.len() - 1]
+            .iter()
+            .map(|node| self.nodes[*node].monomers[0])
+            .collect();
+        Some(CircularPolymer::from(Polymer { monomers }))
+    }
+}
+
+//------------------------------------ UNITIGS ---------------------------------------//
+
+impl<M: Monomer> DeBruijnGraph<M> {
+    pub fn unitigs(&self) -> Vec<Polymer<M>> {
+        let (indegrees, outdegrees) = self.degrees();
+        let simple = |node: usize| indegrees[node] == 1 && outdegrees[node] == 1;
+        let mut visited = vec![false; self.nodes.len()];
+        let mut unitigs = Vec::new();
+        for node in 0..self.nodes.len() {
+            if simple(node) {
+                continue;
+            }
+            for target in &self.edges[node] {
+                let mut path = vec![node, *target];
+                let mut current = *target;
+                while simple(current) {
+                    visited[current] = true;
+                    current = self.edges[current][0];
+                    path.push(current);
+                }
+                unitigs.push(self.spell(&path));
+            }
+        }
+        for node in 0..self.nodes.len() {
+            if simple(node) && !visited[node] {
+                let mut path = vec![node];
+                let mut current = node;
+                loop {
+                    visited[current] = true;
+                    current = self.edges[current][0];
+                    path.push(current);
+                    if current == node {
+                        break;
+                    }
+                }
+                unitigs.push(self.spell(&path));
+            }
+        }
+        unitigs
+    }
+}
+
+impl<N: Nucleotide> DeBruijnGraph<N> {
+    pub fn contigs(&self, min_length: usize) -> Vec<Polymer<N>> {
+        let contigs = self
+            .unitigs()
+            .into_iter()
+            .filter(|unitig| unitig.len() >= min_length)
+            .map(|unitig| {
+                let complement = unitig.reverse_complement();
+                unitig.min(complement)
+            })
+            .collect::<BTreeSet<Polymer<N>>>();
+        contigs.into_iter().collect()
+    }
+}
@@ -0,0 +1,149 @@
+use crate::kmers::encode;
+use crate::monomers::Nucleotide;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use core::fmt;
+
+//--------------------------------- DISTANCE ERROR -----------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum DistanceError {
+    Empty,
+    LengthMismatch,
+    Saturated,
+}
+
+impl fmt::Display for DistanceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DistanceError::Empty => write!(f, "polymers are empty"),
+            DistanceError::LengthMismatch => write!(f, "polymers are not aligned"),
+            DistanceError::Saturated => write!(f, "substitutions are saturated"),
+        }
+    }
+}
+
+impl std::error::Error for DistanceError {}
+
+fn logarithm(value: f64) -> Result<f64, DistanceError> {
+    if value > 0.0 {
+        Ok(value.ln())
+    } else {
+        Err(DistanceError::Saturated)
+    }
+}
+
+//----------------------------------- DISTANCES --------------------------------------//
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    fn aligned_length(&self, other: &Self) -> Result<f64, DistanceError> {
+        if self.monomers.len() != other.monomers.len() {
+            Err(DistanceError::LengthMismatch)
+        } else if self.monomers.is_empty() {
+            Err(DistanceError::Empty)
+        } else {
+            Ok(self.monomers.len() as f64)
+        }
+    }
+    fn substitutions(&self, other: &Self) -> Result<(f64, f64), DistanceError> {
+        let length = self.aligned_length(other)?;
+        let mut transitions = 0.0;
+        let mut transversions = 0.0;
+        for (a, b) in self.monomers.iter().zip(other.monomers.iter()) {
+            if a != b {
+                if a.is_purine() == b.is_purine() {
+                    transitions += 1.0;
+                } else {
+                    transversions += 1.0;
+                }
+            }
+        }
+        Ok((transitions / length, transversions / length))
+    }
+    pub fn p_distance(&self, other: &Self) -> Result<f64, DistanceError> {
+        let length = self.aligned_length(other)?;
+        Ok(self.hamming_distance(other) as f64 / length)
+    }
+    pub fn jukes_cantor_distance(&self, other: &Self) -> Result<f64, DistanceError> {
+        let p = self.p_distance(other)?;
+        Ok(-0.75 * logarithm(1.0 - 4.0 * p / 3.0)?)
+    }
+    pub fn kimura_distance(&self, other: &Self) -> Result<f64, DistanceError> {
+        let (p, q) = self.substitutions(other)?;
+        Ok(-0.5 * logarithm(1.0 - 2.0 * p - q)? - 0.25 * logarithm(1.0 - 2.0 * q)?)
+    }
+    pub fn tamura_distance(&self, other: &Self) -> Result<f64, DistanceError> {
+        let (p, q) = self.substitutions(other)?;
+        let gc1 = self.gc_content() / 100.0;
+        let gc2 = other.gc_content() / 100.0;
+        let c = gc1 + gc2 - 2.0 * gc1 * gc2;
+        if c <= 0.0 {
+            return Err(DistanceError::Saturated);
+        }
+        Ok(-c * logarithm(1.0 - p / c - q)? - 0.5 * (1.0 - c) * logarithm(1.0 - 2.0 * q)?)
+    }
+    pub fn logdet_distance(&self, other: &Self) -> Result<f64, DistanceError> {
+        let length = self.aligned_length(other)?;
+        let size = N::ALPHABET.len();
+        let mut divergence = vec![vec![0.0; size]; size];
+        for (a, b) in self.monomers.iter().zip(other.monomers.iter()) {
+            divergence[encode(a) as usize][encode(b) as usize] += 1.0 / length;
+        }
+        let rows = divergence.iter().map(|r| r.iter().sum::<f64>());
+        let columns = (0..size).map(|j| divergence.iter().map(|r| r[j]).sum::<f64>());
+        let mut frequencies = 0.0;
+        for frequency in rows.chain(columns) {
+            frequencies += logarithm(frequency)?;
+        }
+        let determinant = logarithm(determinant(divergence))?;
+        Ok(-(determinant - 0.5 * frequencies) / size as f64)
+    }
+}
+
+fn determinant(mut matrix: Vec<Vec<f64>>) -> f64 {
+    let size = matrix.len();
+    let mut determinant = 1.0;
+    for i in 0..size {
+        let pivot = (i..size)
+            .max_by(|a, b| matrix[*a][i].abs().total_cmp(&matrix[*b][i].abs()))
+            .unwrap();
+        if matrix[pivot][i] == 0.0 {
+            return 0.0;
+        }
+        if pivot != i {
+            matrix.swap(pivot, i);
+            determinant = -determinant;
+        }
+        determinant *= matrix[i][i];
+        let (pivots, rows) = matrix.split_at_mut(i + 1);
+        let pivot_row = &pivots[i];
+        for row in rows {
+            let factor = row[i] / pivot_row[i];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row.iter()).skip(i) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+    determinant
+}
+
+//-------------------------------- DISTANCE MATRIX -----------------------------------//
+
+pub type Distance<N> = fn(&PolymerSlice<N>, &PolymerSlice<N>) -> Result<f64, DistanceError>;
+
+impl<N: Nucleotide> Polymer<N> {
+    pub fn distance_matrix(
+        polymers: &[Polymer<N>],
+        distance: Distance<N>,
+    ) -> Result<Vec<Vec<f64>>, DistanceError> {
+        let mut matrix = vec![vec![0.0; polymers.len()]; polymers.len()];
+        for i in 0..polymers.len() {
+            for j in i + 1..polymers.len() {
+                let d = distance(&polymers[i], &polymers[j])?;
+                matrix[i][j] = d;
+                matrix[j][i] = d;
+            }
+        }
+        Ok(matrix)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use core::ops::Range;
+
+//-------------------------------------- EDIT ----------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Edit<M: Monomer> {
+    pub position: usize,
+    pub removed: Vec<M>,
+    pub inserted: Vec<M>,
+}
+
+impl<M: Monomer> Edit<M> {
+    pub fn is_substitution(&self) -> bool {
+        self.removed.len() == 1 && self.inserted.len() == 1
+    }
+    pub fn is_insertion(&self) -> bool {
+        self.removed.is_empty() && !self.inserted.is_empty()
+    }
+    pub fn is_deletion(&self) -> bool {
+        !self.removed.is_empty() && self.inserted.is_empty()
+    }
+    pub fn inverse(&self) -> Edit<M> {
+        Edit {
+            position: self.position,
+            removed: self.inserted.clone(),
+            inserted: self.removed.clone(),
+        }
+    }
+}
+
+//------------------------------------- EDITING --------------------------------------//
+
+impl<M: Monomer> Polymer<M> {
+    pub fn substitute(&mut self, index: usize, monomer: M) -> Option<Edit<M>> {
+        self.replace(
+            index..index.checked_add(1)?,
+            PolymerSlice::from_monomers(&[monomer]),
+        )
+    }
+    pub fn insert(&mut self, index: usize, polymer: &PolymerSlice<M>) -> Option<Edit<M>> {
+        self.replace(index..index, polymer)
+    }
+    pub fn delete(&mut self, range: Range<usize>) -> Option<Edit<M>> {
+        self.replace(range, PolymerSlice::from_monomers(&[]))
+    }
+    pub fn concatenate(&mut self, polymer: &PolymerSlice<M>) -> Edit<M> {
+        let position = self.monomers.len();
+        self.monomers.extend_from_slice(&polymer.monomers);
+        Edit {
+            position,
+            removed: Vec::new(),
+            inserted: polymer.monomers.to_vec(),
+        }
+    }
+    pub fn replace(&mut self, range: Range<usize>, polymer: &PolymerSlice<M>) -> Option<Edit<M>> {
+        if range.start > range.end || range.end > self.monomers.len() {
+            return None;
+        }
+        if range.len() == self.monomers.len() && polymer.monomers.is_empty() {
+            return None;
+        }
+        let position = range.start;
+        let removed = self
+            .monomers
+            .splice(range, polymer.monomers.iter().copied())
+            .collect();
+        Some(Edit {
+            position,
+            removed,
+            inserted: polymer.monomers.to_vec(),
+        })
+    }
+    pub fn apply(&mut self, edit: &Edit<M>) -> bool {
+        let Some(end) = edit.position.checked_add(edit.removed.len()) else {
+            return false;
+        };
+        let range = edit.position..end;
+        if self.monomers.get(range.clone()) != Some(&edit.removed[..]) {
+            return false;
+        }
+        if range.len() == self.monomers.len() && edit.inserted.is_empty() {
+            return false;
+        }
+        self.monomers.splice(range, edit.inserted.iter().copied());
+        true
+    }
+    pub fn undo(&mut self, edit: &Edit<M>) -> bool {
+        self.apply(&edit.inverse())
+    }
+}
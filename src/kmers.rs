@@ -0,0 +1,215 @@
+use crate::monomers::Monomer;
+use crate::monomers::Nucleotide;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use std::collections::HashMap;
+
+//------------------------------------- K-MERS ---------------------------------------//
+
+const MAX_COMPOSITION: usize = 1 << 24;
+
+pub struct Kmers<'a, M: Monomer> {
+    monomers: &'a [M],
+    k: usize,
+    position: usize,
+}
+
+impl<'a, M: Monomer> Iterator for Kmers<'a, M> {
+    type Item = &'a PolymerSlice<M>;
+    fn next(&mut self) -> Option<&'a PolymerSlice<M>> {
+        if self.k == 0 || self.position + self.k > self.monomers.len() {
+            None
+        } else {
+            let kmer = &self.monomers[self.position..self.position + self.k];
+            self.position += 1;
+            Some(PolymerSlice::from_monomers(kmer))
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.k == 0 {
+            0
+        } else {
+            (self.monomers.len() + 1).saturating_sub(self.position + self.k)
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<M: Monomer> ExactSizeIterator for Kmers<'_, M> {}
+
+pub fn kmer_rank<M: Monomer>(kmer: &PolymerSlice<M>) -> Option<usize> {
+    let size = M::ALPHABET.len();
+    kmer.iter()
+        .map(|m| M::ALPHABET.binary_search(m).unwrap())
+        .try_fold(0usize, |rank, index| {
+            rank.checked_mul(size)?.checked_add(index)
+        })
+}
+
+pub fn kmer_unrank<M: Monomer>(rank: usize, k: usize) -> Polymer<M> {
+    let size = M::ALPHABET.len();
+    let mut monomers = vec![M::ALPHABET[0]; k];
+    let mut rank = rank;
+    for monomer in monomers.iter_mut().rev() {
+        *monomer = M::ALPHABET[rank % size];
+        rank /= size;
+    }
+    Polymer { monomers }
+}
+
+impl<M: Monomer> PolymerSlice<M> {
+    pub fn kmers(&self, k: usize) -> Kmers<'_, M> {
+        Kmers {
+            monomers: &self.monomers,
+            k,
+            position: 0,
+        }
+    }
+    pub fn kmer_count(&self, k: usize) -> HashMap<&PolymerSlice<M>, usize> {
+        let mut count = HashMap::new();
+        for kmer in self.kmers(k) {
+            *count.entry(kmer).or_insert(0) += 1;
+        }
+        count
+    }
+    pub fn kmer_composition(&self, k: usize) -> Option<Vec<usize>> {
+        let size = M::ALPHABET
+            .len()
+            .checked_pow(u32::try_from(k).ok()?)
+            .filter(|size| *size <= MAX_COMPOSITION)?;
+        let mut composition = vec![0; size];
+        for kmer in self.kmers(k) {
+            composition[kmer_rank(kmer)?] += 1;
+        }
+        Some(composition)
+    }
+}
+
+impl<M: Monomer> Polymer<M> {
+    pub fn all_kmers(alphabet: &[M], k: usize) -> Vec<Polymer<M>> {
+        let mut kmers = vec![Vec::new()];
+        for _ in 0..k {
+            kmers = kmers
+                .iter()
+                .flat_map(|prefix| {
+                    alphabet.iter().map(move |monomer| {
+                        let mut kmer = prefix.clone();
+                        kmer.push(*monomer);
+                        kmer
+                    })
+                })
+                .collect();
+        }
+        kmers
+            .into_iter()
+            .filter(|monomers| !monomers.is_empty())
+            .map(|monomers| Polymer { monomers })
+            .collect()
+    }
+    pub fn all_kmers_up_to(alphabet: &[M], k: usize) -> Vec<Polymer<M>> {
+        let mut kmers = Vec::new();
+        let mut stack = vec![Vec::new()];
+        while let Some(monomers) = stack.pop() {
+            if monomers.len() < k {
+                for monomer in alphabet.iter().rev() {
+                    let mut kmer = monomers.clone();
+                    kmer.push(*monomer);
+                    stack.push(kmer);
+                }
+            }
+            if !monomers.is_empty() {
+                kmers.push(Polymer { monomers });
+            }
+        }
+        kmers
+    }
+}
+
+//--------------------------------- ENCODED K-MERS -----------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct EncodedKmer {
+    pub position: usize,
+    pub forward: u64,
+    pub reverse: u64,
+}
+
+impl EncodedKmer {
+    pub fn canonical(&self) -> u64 {
+        self.forward.min(self.reverse)
+    }
+}
+
+pub struct EncodedKmers<'a, N: Nucleotide> {
+    monomers: &'a [N],
+    k: usize,
+    position: usize,
+    forward: u64,
+    reverse: u64,
+    mask: u64,
+}
+
+impl<N: Nucleotide> Iterator for EncodedKmers<'_, N> {
+    type Item = EncodedKmer;
+    fn next(&mut self) -> Option<EncodedKmer> {
+        while self.position < self.monomers.len() {
+            let nucleotide = self.monomers[self.position];
+            let code = encode(&nucleotide);
+            let complement = encode(&nucleotide.complement());
+            self.forward = ((self.forward << 2) | code) & self.mask;
+            self.reverse = (self.reverse >> 2) | (complement << (2 * (self.k - 1)));
+            self.position += 1;
+            if self.position >= self.k {
+                return Some(EncodedKmer {
+                    position: self.position - self.k + 1,
+                    forward: self.forward,
+                    reverse: self.reverse,
+                });
+            }
+        }
+        None
+    }
+}
+
+pub(crate) fn encode<N: Nucleotide>(nucleotide: &N) -> u64 {
+    N::ALPHABET.binary_search(nucleotide).unwrap() as u64
+}
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn encoded_kmers(&self, k: usize) -> Option<EncodedKmers<'_, N>> {
+        if k == 0 || k > 32 {
+            None
+        } else {
+            let mask = if k == 32 {
+                u64::MAX
+            } else {
+                (1 << (2 * k)) - 1
+            };
+            Some(EncodedKmers {
+                monomers: &self.monomers,
+                k,
+                position: 0,
+                forward: 0,
+                reverse: 0,
+                mask,
+            })
+        }
+    }
+}
+
+//-------------------------------- CANONICAL K-MERS ----------------------------------//
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn canonical_kmers(&self, k: usize) -> Vec<Polymer<N>> {
+        self.kmers(k)
+            .map(|kmer| kmer.to_polymer().min(kmer.reverse_complement()))
+            .collect()
+    }
+    pub fn canonical_kmer_count(&self, k: usize) -> Option<HashMap<u64, usize>> {
+        let mut count = HashMap::new();
+        for kmer in self.encoded_kmers(k)? {
+            *count.entry(kmer.canonical()).or_insert(0) += 1;
+        }
+        Some(count)
+    }
+}
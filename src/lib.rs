@@ -1,2 +1,24 @@
+pub mod assembly;
+pub mod circular;
+pub mod correction;
+pub mod debruijn;
+pub mod distances;
+pub mod edits;
+pub mod kmers;
+pub mod masses;
+pub mod metrics;
 pub mod monomers;
+pub mod overlaps;
+pub mod packed;
+pub mod parsimony;
 pub mod polymers;
+pub mod probability;
+pub mod proteases;
+pub mod random;
+pub mod records;
+pub mod restriction;
+pub mod sketches;
+pub mod spectra;
+pub mod splits;
+pub mod trees;
+pub mod trie;
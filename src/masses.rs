@@ -0,0 +1,149 @@
+use crate::monomers::AminoAcid;
+use crate::monomers::Monomer;
+use crate::polymers::ProteinSlice;
+
+//------------------------------------- MASSES ---------------------------------------//
+
+pub const PROTON: f64 = 1.007_276;
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum MassType {
+    Monoisotopic,
+    Average,
+}
+
+impl MassType {
+    pub fn residue(&self, aminoacid: &AminoAcid) -> f64 {
+        match self {
+            MassType::Monoisotopic => aminoacid.mass(),
+            MassType::Average => aminoacid.average_mass(),
+        }
+    }
+    pub fn water(&self) -> f64 {
+        match self {
+            MassType::Monoisotopic => 18.010_565,
+            MassType::Average => 18.015_28,
+        }
+    }
+}
+
+//---------------------------------- MODIFICATIONS -----------------------------------//
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub struct Modification {
+    pub name: &'static str,
+    pub residues: &'static str,
+    pub monoisotopic_delta: f64,
+    pub average_delta: f64,
+}
+
+pub const MODIFICATIONS: &[Modification] = &[
+    Modification::new("Carbamidomethyl", "C", 57.021_464, 57.051_3),
+    Modification::new("Oxidation", "M", 15.994_915, 15.999_4),
+    Modification::new("Phospho", "STY", 79.966_331, 79.979_9),
+];
+
+impl Modification {
+    pub const fn new(
+        name: &'static str,
+        residues: &'static str,
+        monoisotopic_delta: f64,
+        average_delta: f64,
+    ) -> Self {
+        Modification {
+            name,
+            residues,
+            monoisotopic_delta,
+            average_delta,
+        }
+    }
+    pub fn find(name: &str) -> Option<Modification> {
+        MODIFICATIONS
+            .iter()
+            .find(|m| m.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+    pub fn targets(&self, aminoacid: &AminoAcid) -> bool {
+        self.residues.contains(aminoacid.symbol())
+    }
+    pub fn delta(&self, mass_type: MassType) -> f64 {
+        match mass_type {
+            MassType::Monoisotopic => self.monoisotopic_delta,
+            MassType::Average => self.average_delta,
+        }
+    }
+}
+
+//---------------------------------- PEPTIDE MASS ------------------------------------//
+
+impl ProteinSlice {
+    pub fn peptide_mass(&self, mass_type: MassType, fixed: &[Modification]) -> f64 {
+        let residues = self
+            .monomers
+            .iter()
+            .map(|a| {
+                let deltas = fixed
+                    .iter()
+                    .filter(|m| m.targets(a))
+                    .map(|m| m.delta(mass_type))
+                    .sum::<f64>();
+                mass_type.residue(a) + deltas
+            })
+            .sum::<f64>();
+        residues + mass_type.water()
+    }
+    pub fn protonated_mass(&self, mass_type: MassType, fixed: &[Modification]) -> f64 {
+        self.peptide_mass(mass_type, fixed) + PROTON
+    }
+    pub fn mass_to_charge(
+        &self,
+        charge: usize,
+        mass_type: MassType,
+        fixed: &[Modification],
+    ) -> Option<f64> {
+        if charge == 0 {
+            return None;
+        }
+        let charge = charge as f64;
+        Some((self.peptide_mass(mass_type, fixed) + charge * PROTON) / charge)
+    }
+    pub fn variable_masses(
+        &self,
+        mass_type: MassType,
+        fixed: &[Modification],
+        variable: &[Modification],
+        max_modifications: usize,
+    ) -> Vec<f64> {
+        let sites = self
+            .monomers
+            .iter()
+            .map(|a| {
+                variable
+                    .iter()
+                    .filter(|m| m.targets(a))
+                    .map(|m| m.delta(mass_type))
+                    .collect()
+            })
+            .filter(|deltas: &Vec<f64>| !deltas.is_empty())
+            .collect::<Vec<Vec<f64>>>();
+        let mut deltas = vec![(0.0, 0)];
+        for site in &sites {
+            let extended = deltas
+                .iter()
+                .filter(|(_, count)| *count < max_modifications)
+                .flat_map(|(delta, count)| site.iter().map(move |d| (delta + d, count + 1)))
+                .collect::<Vec<(f64, usize)>>();
+            deltas.extend(extended);
+            deltas.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.total_cmp(&b.0)));
+            deltas.dedup_by(|a, b| a.1 == b.1 && (a.0 - b.0).abs() < 1e-9);
+        }
+        let base = self.peptide_mass(mass_type, fixed);
+        let mut masses = deltas
+            .into_iter()
+            .map(|(delta, _)| base + delta)
+            .collect::<Vec<f64>>();
+        masses.sort_by(f64::total_cmp);
+        masses.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+        masses
+    }
+}
@@ -0,0 +1,117 @@
+use crate::monomers::DnaNucleotide;
+use crate::monomers::Monomer;
+use crate::polymers::Dna;
+use crate::polymers::Polymer;
+
+//------------------------------- ASSEMBLY METRICS -----------------------------------//
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct AssemblyReport {
+    pub contigs: usize,
+    pub total_length: usize,
+    pub largest_contig: usize,
+    pub n50: usize,
+    pub n75: usize,
+    pub ng50: Option<usize>,
+    pub l50: usize,
+    pub gc_content: f64,
+    pub n_runs: usize,
+    pub n_bases: usize,
+}
+
+fn nx(lengths: &[usize], total: usize, x: usize) -> Option<(usize, usize)> {
+    let mut covered = 0;
+    for (i, length) in lengths.iter().enumerate() {
+        covered += length;
+        if covered * 100 >= total * x {
+            return Some((*length, i + 1));
+        }
+    }
+    None
+}
+
+impl AssemblyReport {
+    pub fn new(contigs: &[Dna], genome_size: Option<usize>) -> Option<Self> {
+        AssemblyReport::with_gaps(contigs, genome_size, 0, 0)
+    }
+    pub fn from_scaffolds(scaffolds: &[&str], genome_size: Option<usize>) -> Option<Self> {
+        let mut contigs = Vec::new();
+        let mut n_runs = 0;
+        let mut n_bases = 0;
+        for scaffold in scaffolds {
+            let mut gap = false;
+            for symbol in scaffold.chars() {
+                if symbol == 'N' {
+                    n_bases += 1;
+                    n_runs += usize::from(!gap);
+                }
+                gap = symbol == 'N';
+            }
+            for contig in scaffold.split('N').filter(|c| !c.is_empty()) {
+                contigs.push(Polymer::new(contig, DnaNucleotide::new)?);
+            }
+        }
+        AssemblyReport::with_gaps(&contigs, genome_size, n_runs, n_bases)
+    }
+    fn with_gaps(
+        contigs: &[Dna],
+        genome_size: Option<usize>,
+        n_runs: usize,
+        n_bases: usize,
+    ) -> Option<Self> {
+        let mut lengths = contigs.iter().map(|c| c.len()).collect::<Vec<usize>>();
+        lengths.sort_unstable_by(|a, b| b.cmp(a));
+        let total_length = lengths.iter().sum::<usize>();
+        if total_length == 0 {
+            return None;
+        }
+        let (n50, l50) = nx(&lengths, total_length, 50)?;
+        let (n75, _) = nx(&lengths, total_length, 75)?;
+        let ng50 = genome_size.and_then(|size| nx(&lengths, size, 50).map(|n| n.0));
+        let gc = contigs
+            .iter()
+            .filter(|c| !c.is_empty())
+            .map(|c| c.gc_content() * c.len() as f64)
+            .sum::<f64>();
+        Some(AssemblyReport {
+            contigs: contigs.len(),
+            total_length,
+            largest_contig: lengths[0],
+            n50,
+            n75,
+            ng50,
+            l50,
+            gc_content: gc / total_length as f64,
+            n_runs,
+            n_bases,
+        })
+    }
+    fn rows(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Contigs", self.contigs.to_string()),
+            ("Total length", self.total_length.to_string()),
+            ("Largest contig", self.largest_contig.to_string()),
+            ("N50", self.n50.to_string()),
+            ("N75", self.n75.to_string()),
+            ("NG50", self.ng50.map_or("-".to_string(), |n| n.to_string())),
+            ("L50", self.l50.to_string()),
+            ("GC (%)", format!("{:.2}", self.gc_content)),
+            ("N runs", self.n_runs.to_string()),
+            ("N bases", self.n_bases.to_string()),
+        ]
+    }
+    pub fn to_table(&self) -> String {
+        let rows = self.rows();
+        let width = rows.iter().map(|r| r.1.len()).max().unwrap_or(0);
+        rows.iter()
+            .map(|(metric, value)| format!("{:<16}{:>width$}\n", metric, value))
+            .collect()
+    }
+    pub fn to_tsv(&self) -> String {
+        let mut tsv = String::from("metric\tvalue\n");
+        for (metric, value) in self.rows() {
+            tsv.push_str(&format!("{}\t{}\n", metric, value));
+        }
+        tsv
+    }
+}
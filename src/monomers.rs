@@ -3,8 +3,12 @@ use core::hash::Hash;
 
 //------------------------------------ MONOMER ---------------------------------------//
 
-pub trait Monomer: Sized + PartialEq + Eq + Hash + Copy + Clone + Ord + PartialOrd + Debug {
+pub trait Monomer:
+    Sized + PartialEq + Eq + Hash + Copy + Clone + Ord + PartialOrd + Debug + 'static
+{
+    const ALPHABET: &'static [Self];
     fn new(symbol: char) -> Option<Self>;
+    fn symbol(&self) -> char;
 }
 
 //----------------------------------- NUCLEOTIDE -------------------------------------//
@@ -27,6 +31,12 @@ pub enum DnaNucleotide {
 }
 
 impl Monomer for DnaNucleotide {
+    const ALPHABET: &'static [DnaNucleotide] = &[
+        DnaNucleotide::Adenine,
+        DnaNucleotide::Cytosine,
+        DnaNucleotide::Guanine,
+        DnaNucleotide::Thymine,
+    ];
     fn new(symbol: char) -> Option<DnaNucleotide> {
         match symbol {
             'A' => Some(DnaNucleotide::Adenine),
@@ -36,6 +46,14 @@ impl Monomer for DnaNucleotide {
             _ => None,
         }
     }
+    fn symbol(&self) -> char {
+        match &self {
+            DnaNucleotide::Adenine => 'A',
+            DnaNucleotide::Cytosine => 'C',
+            DnaNucleotide::Guanine => 'G',
+            DnaNucleotide::Thymine => 'T',
+        }
+    }
 }
 
 impl Nucleotide for DnaNucleotide {
@@ -80,6 +98,12 @@ pub enum RnaNucleotide {
 }
 
 impl Monomer for RnaNucleotide {
+    const ALPHABET: &'static [RnaNucleotide] = &[
+        RnaNucleotide::Adenine,
+        RnaNucleotide::Cytosine,
+        RnaNucleotide::Guanine,
+        RnaNucleotide::Uracil,
+    ];
     fn new(symbol: char) -> Option<RnaNucleotide> {
         match symbol {
             'A' => Some(RnaNucleotide::Adenine),
@@ -89,6 +113,14 @@ impl Monomer for RnaNucleotide {
             _ => None,
         }
     }
+    fn symbol(&self) -> char {
+        match &self {
+            RnaNucleotide::Adenine => 'A',
+            RnaNucleotide::Cytosine => 'C',
+            RnaNucleotide::Guanine => 'G',
+            RnaNucleotide::Uracil => 'U',
+        }
+    }
 }
 
 impl Nucleotide for RnaNucleotide {
@@ -149,6 +181,28 @@ pub enum AminoAcid {
 }
 
 impl Monomer for AminoAcid {
+    const ALPHABET: &'static [AminoAcid] = &[
+        AminoAcid::Alanine,
+        AminoAcid::Cysteine,
+        AminoAcid::AsparticAcid,
+        AminoAcid::GlutamicAcid,
+        AminoAcid::Phenylalanine,
+        AminoAcid::Glycine,
+        AminoAcid::Histidine,
+        AminoAcid::Isoleucine,
+        AminoAcid::Lysine,
+        AminoAcid::Leucine,
+        AminoAcid::Methionine,
+        AminoAcid::Asparagine,
+        AminoAcid::Proline,
+        AminoAcid::Glutamine,
+        AminoAcid::Arginine,
+        AminoAcid::Serine,
+        AminoAcid::Threonine,
+        AminoAcid::Valine,
+        AminoAcid::Tryptophan,
+        AminoAcid::Tyrosine,
+    ];
     fn new(symbol: char) -> Option<AminoAcid> {
         match symbol {
             'A' => Some(AminoAcid::Alanine),
@@ -174,6 +228,30 @@ impl Monomer for AminoAcid {
             _ => None,
         }
     }
+    fn symbol(&self) -> char {
+        match &self {
+            AminoAcid::Alanine => 'A',
+            AminoAcid::Cysteine => 'C',
+            AminoAcid::AsparticAcid => 'D',
+            AminoAcid::GlutamicAcid => 'E',
+            AminoAcid::Phenylalanine => 'F',
+            AminoAcid::Glycine => 'G',
+            AminoAcid::Histidine => 'H',
+            AminoAcid::Isoleucine => 'I',
+            AminoAcid::Lysine => 'K',
+            AminoAcid::Leucine => 'L',
+            AminoAcid::Methionine => 'M',
+            AminoAcid::Asparagine => 'N',
+            AminoAcid::Proline => 'P',
+            AminoAcid::Glutamine => 'Q',
+            AminoAcid::Arginine => 'R',
+            AminoAcid::Serine => 'S',
+            AminoAcid::Threonine => 'T',
+            AminoAcid::Valine => 'V',
+            AminoAcid::Tryptophan => 'W',
+            AminoAcid::Tyrosine => 'Y',
+        }
+    }
 }
 
 impl AminoAcid {
@@ -201,6 +279,30 @@ impl AminoAcid {
             AminoAcid::Tyrosine => 163.063_33,
         }
     }
+    pub fn average_mass(&self) -> f64 {
+        match &self {
+            AminoAcid::Alanine => 71.078_8,
+            AminoAcid::Cysteine => 103.138_8,
+            AminoAcid::AsparticAcid => 115.088_6,
+            AminoAcid::GlutamicAcid => 129.115_5,
+            AminoAcid::Phenylalanine => 147.176_6,
+            AminoAcid::Glycine => 57.051_9,
+            AminoAcid::Histidine => 137.141_1,
+            AminoAcid::Isoleucine => 113.159_4,
+            AminoAcid::Lysine => 128.174_1,
+            AminoAcid::Leucine => 113.159_4,
+            AminoAcid::Methionine => 131.192_6,
+            AminoAcid::Asparagine => 114.103_8,
+            AminoAcid::Proline => 97.116_7,
+            AminoAcid::Glutamine => 128.130_7,
+            AminoAcid::Arginine => 156.187_5,
+            AminoAcid::Serine => 87.078_2,
+            AminoAcid::Threonine => 101.105_1,
+            AminoAcid::Valine => 99.132_6,
+            AminoAcid::Tryptophan => 186.213_2,
+            AminoAcid::Tyrosine => 163.176_0,
+        }
+    }
     pub fn is_start(&self) -> bool {
         matches!(&self, AminoAcid::Methionine)
     }
@@ -0,0 +1,87 @@
+use crate::monomers::Monomer;
+use crate::records::Record;
+use std::collections::HashMap;
+
+//--------------------------------- OVERLAP GRAPH ------------------------------------//
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct OverlapGraph {
+    ids: Vec<String>,
+    edges: Vec<(usize, usize, usize)>,
+}
+
+impl OverlapGraph {
+    pub fn new<M: Monomer>(records: &[Record<M>], k: usize) -> Self {
+        let mut prefixes: HashMap<&[M], Vec<usize>> = HashMap::new();
+        for (i, record) in records.iter().enumerate() {
+            if k > 0 && record.polymer.len() >= k {
+                prefixes
+                    .entry(&record.polymer.monomers[..k])
+                    .or_default()
+                    .push(i);
+            }
+        }
+        let mut edges = Vec::new();
+        for (i, record) in records.iter().enumerate() {
+            let length = record.polymer.len();
+            if k == 0 || length < k {
+                continue;
+            }
+            if let Some(targets) = prefixes.get(&record.polymer.monomers[length - k..]) {
+                edges.extend(targets.iter().filter(|j| **j != i).map(|j| (i, *j, k)));
+            }
+        }
+        OverlapGraph::from_edges(records, edges)
+    }
+    pub fn with_min_overlap<M: Monomer>(records: &[Record<M>], min_overlap: usize) -> Self {
+        let mut edges = Vec::new();
+        for (i, source) in records.iter().enumerate() {
+            for (j, target) in records.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let source = &source.polymer.monomers;
+                let target = &target.polymer.monomers;
+                let longest = source.len().min(target.len());
+                let overlap = (min_overlap.max(1)..=longest)
+                    .rev()
+                    .find(|k| source[source.len() - k..] == target[..*k]);
+                if let Some(overlap) = overlap {
+                    edges.push((i, j, overlap));
+                }
+            }
+        }
+        OverlapGraph::from_edges(records, edges)
+    }
+    fn from_edges<M: Monomer>(
+        records: &[Record<M>],
+        mut edges: Vec<(usize, usize, usize)>,
+    ) -> Self {
+        edges.sort_unstable();
+        let ids = records.iter().map(|r| r.id.clone()).collect();
+        OverlapGraph { ids, edges }
+    }
+    pub fn adjacency_list(&self) -> Vec<(&str, &str, usize)> {
+        self.edges
+            .iter()
+            .map(|(i, j, overlap)| (self.ids[*i].as_str(), self.ids[*j].as_str(), *overlap))
+            .collect()
+    }
+    pub fn to_edge_list(&self) -> String {
+        self.adjacency_list()
+            .iter()
+            .map(|(source, target, _)| format!("{} {}\n", source, target))
+            .collect()
+    }
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph overlaps {\n");
+        for (source, target, overlap) in self.adjacency_list() {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                source, target, overlap
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,174 @@
+use crate::kmers::encode;
+use crate::monomers::DnaNucleotide;
+use crate::monomers::Nucleotide;
+use crate::monomers::RnaNucleotide;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use core::marker::PhantomData;
+use std::collections::HashMap;
+
+//--------------------------------- PACKED POLYMER -----------------------------------//
+
+const BASES_PER_WORD: usize = 32;
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct PackedPolymer<N: Nucleotide> {
+    words: Vec<u64>,
+    len: usize,
+    nucleotide: PhantomData<N>,
+}
+
+fn decode<N: Nucleotide>(code: u64) -> N {
+    N::ALPHABET[code as usize]
+}
+
+impl<N: Nucleotide> PackedPolymer<N> {
+    fn from_codes(codes: impl Iterator<Item = u64>) -> Self {
+        let mut words = Vec::new();
+        let mut len = 0;
+        for code in codes {
+            if len % BASES_PER_WORD == 0 {
+                words.push(0);
+            }
+            words[len / BASES_PER_WORD] |= code << (2 * (len % BASES_PER_WORD));
+            len += 1;
+        }
+        PackedPolymer {
+            words,
+            len,
+            nucleotide: PhantomData,
+        }
+    }
+    fn code(&self, index: usize) -> u64 {
+        (self.words[index / BASES_PER_WORD] >> (2 * (index % BASES_PER_WORD))) & 0b11
+    }
+    fn mask(&self, word: usize, len: usize) -> u64 {
+        let bases = len - word * BASES_PER_WORD;
+        if bases >= BASES_PER_WORD {
+            LOW_BITS
+        } else {
+            LOW_BITS & ((1 << (2 * bases)) - 1)
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn get(&self, index: usize) -> Option<N> {
+        if index < self.len {
+            Some(decode(self.code(index)))
+        } else {
+            None
+        }
+    }
+    pub fn monomer_count(&self) -> HashMap<N, usize> {
+        let mut counts = [0; 4];
+        for (word_index, word) in self.words.iter().enumerate() {
+            let mask = self.mask(word_index, self.len);
+            let high = (word >> 1) & mask;
+            let low = word & mask;
+            let matches = [!high & !low & mask, !high & low, high & !low, high & low];
+            for (count, bits) in counts.iter_mut().zip(matches) {
+                *count += bits.count_ones() as usize;
+            }
+        }
+        counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .map(|(code, count)| (decode(code as u64), count))
+            .collect()
+    }
+    pub fn gc_content(&self) -> f64 {
+        let count = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(word_index, word)| {
+                (((word >> 1) ^ word) & self.mask(word_index, self.len)).count_ones() as usize
+            })
+            .sum::<usize>() as f64;
+        let length = self.len as f64;
+        count * 100.0 / length
+    }
+    pub fn reverse_complement(&self) -> PackedPolymer<N> {
+        let complements = N::ALPHABET
+            .iter()
+            .map(|n| encode(&n.complement()))
+            .collect::<Vec<u64>>();
+        let codes = (0..self.len)
+            .rev()
+            .map(|i| complements[self.code(i) as usize]);
+        PackedPolymer::from_codes(codes)
+    }
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        let len = self.len.min(other.len);
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .enumerate()
+            .take(len.div_ceil(BASES_PER_WORD))
+            .map(|(word_index, (a, b))| {
+                let diff = a ^ b;
+                ((diff | (diff >> 1)) & self.mask(word_index, len)).count_ones() as usize
+            })
+            .sum()
+    }
+    pub fn motif_locations(&self, motif: &Self) -> Vec<usize> {
+        let size = motif.len;
+        if size == 0 || size > self.len {
+            return Vec::new();
+        }
+        if size > BASES_PER_WORD {
+            return (0..=self.len - size)
+                .filter(|i| (0..size).all(|j| self.code(i + j) == motif.code(j)))
+                .map(|i| i + 1)
+                .collect();
+        }
+        let window_mask = if size == BASES_PER_WORD {
+            u64::MAX
+        } else {
+            (1 << (2 * size)) - 1
+        };
+        let target = (0..size).fold(0, |acc, j| acc | (motif.code(j) << (2 * j)));
+        let mut window = 0;
+        let mut locations = Vec::new();
+        for i in 0..self.len {
+            window = (window >> 2) | (self.code(i) << (2 * (size - 1)));
+            if i + 1 >= size && window & window_mask == target {
+                locations.push(i + 2 - size);
+            }
+        }
+        locations
+    }
+}
+
+impl<N: Nucleotide> From<&Polymer<N>> for PackedPolymer<N> {
+    fn from(polymer: &Polymer<N>) -> Self {
+        PackedPolymer::from(&**polymer)
+    }
+}
+
+impl<N: Nucleotide> From<&PolymerSlice<N>> for PackedPolymer<N> {
+    fn from(polymer: &PolymerSlice<N>) -> Self {
+        PackedPolymer::from_codes(polymer.monomers.iter().map(encode))
+    }
+}
+
+impl<N: Nucleotide> From<&PackedPolymer<N>> for Polymer<N> {
+    fn from(packed: &PackedPolymer<N>) -> Self {
+        let monomers = (0..packed.len).map(|i| decode(packed.code(i))).collect();
+        Polymer { monomers }
+    }
+}
+
+//----------------------------------- PACKED DNA -------------------------------------//
+
+pub type PackedDna = PackedPolymer<DnaNucleotide>;
+
+//----------------------------------- PACKED RNA -------------------------------------//
+
+pub type PackedRna = PackedPolymer<RnaNucleotide>;
@@ -0,0 +1,100 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::trees::Tree;
+use std::collections::HashMap;
+
+//-------------------------------- SMALL PARSIMONY -----------------------------------//
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Parsimony<M: Monomer> {
+    pub score: usize,
+    pub ancestors: HashMap<usize, Polymer<M>>,
+}
+
+impl Tree {
+    pub fn small_parsimony<M: Monomer>(
+        &self,
+        leaves: &HashMap<String, Polymer<M>>,
+    ) -> Option<Parsimony<M>> {
+        self.weighted_parsimony(leaves, |a, b| usize::from(a != b))
+    }
+    pub fn weighted_parsimony<M: Monomer>(
+        &self,
+        leaves: &HashMap<String, Polymer<M>>,
+        cost: fn(M, M) -> usize,
+    ) -> Option<Parsimony<M>> {
+        let order = self.preorder();
+        let mut sequences = HashMap::new();
+        for id in &order {
+            let node = self.node(*id)?;
+            if node.is_leaf() {
+                sequences.insert(*id, &leaves.get(node.label()?)?.monomers);
+            }
+        }
+        let length = sequences.values().next()?.len();
+        if sequences.values().any(|s| s.len() != length) {
+            return None;
+        }
+        let alphabet = M::ALPHABET;
+        let mut score = 0;
+        let mut ancestors = HashMap::new();
+        let mut costs = vec![vec![0; alphabet.len()]; self.len()];
+        let mut choices = vec![0; self.len()];
+        for site in 0..length {
+            for id in order.iter().rev() {
+                costs[*id] = match sequences.get(id) {
+                    Some(sequence) => alphabet
+                        .iter()
+                        .map(|m| if *m == sequence[site] { 0 } else { usize::MAX })
+                        .collect(),
+                    None => alphabet
+                        .iter()
+                        .map(|m| {
+                            self.node(*id)
+                                .unwrap()
+                                .children()
+                                .iter()
+                                .map(|child| {
+                                    alphabet
+                                        .iter()
+                                        .zip(&costs[*child])
+                                        .map(|(n, c)| c.saturating_add(cost(*m, *n)))
+                                        .min()
+                                        .unwrap()
+                                })
+                                .fold(0, usize::saturating_add)
+                        })
+                        .collect(),
+                };
+            }
+            let root = self.root();
+            let (best, best_cost) = costs[root].iter().enumerate().min_by_key(|e| e.1).unwrap();
+            score += best_cost;
+            choices[root] = best;
+            for id in &order {
+                let parent = alphabet[choices[*id]];
+                for child in self.node(*id).unwrap().children() {
+                    choices[*child] = alphabet
+                        .iter()
+                        .zip(&costs[*child])
+                        .enumerate()
+                        .min_by_key(|(_, (n, c))| {
+                            (c.saturating_add(cost(parent, **n)), **n != parent)
+                        })
+                        .unwrap()
+                        .0;
+                }
+                if !sequences.contains_key(id) {
+                    ancestors
+                        .entry(*id)
+                        .or_insert_with(|| Polymer {
+                            monomers: Vec::with_capacity(length),
+                        })
+                        .monomers
+                        .push(parent);
+                }
+            }
+        }
+        Some(Parsimony { score, ancestors })
+    }
+}
@@ -4,14 +4,20 @@ use crate::monomers::DnaNucleotide;
 use crate::monomers::Monomer;
 use crate::monomers::Nucleotide;
 use crate::monomers::RnaNucleotide;
+use core::borrow::Borrow;
+use core::fmt;
 use core::hash::Hash;
+use core::ops::Deref;
+use core::ops::Index;
+use core::slice::Iter;
+use core::slice::SliceIndex;
 use std::collections::HashMap;
 
 //------------------------------------ POLYMER ---------------------------------------//
 
 #[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd, Debug)]
 pub struct Polymer<M: Monomer> {
-    monomers: Vec<M>,
+    pub(crate) monomers: Vec<M>,
 }
 
 impl<M: Monomer> Polymer<M> {
@@ -26,30 +32,6 @@ impl<M: Monomer> Polymer<M> {
                 .map(|monomers| Polymer { monomers })
         }
     }
-    pub fn monomer_count(&self) -> HashMap<M, usize> {
-        let mut count = HashMap::new();
-        for monomer in &self.monomers {
-            let prev = count.get(monomer);
-            let current = prev.unwrap_or(&0) + 1;
-            count.insert(*monomer, current);
-        }
-        count
-    }
-    pub fn hamming_distance(&self, other: &Self) -> usize {
-        self.monomers
-            .iter()
-            .zip(other.monomers.iter())
-            .filter(|p| p.0 != p.1)
-            .count()
-    }
-    pub fn motif_locations(&self, motif: &Self) -> Vec<usize> {
-        self.monomers
-            .windows(motif.monomers.len())
-            .enumerate()
-            .filter(|e| e.1 == motif.monomers)
-            .map(|e| e.0 + 1)
-            .collect()
-    }
     pub fn profile(polymers: &[Polymer<M>]) -> HashMap<M, Vec<usize>> {
         let mut profile = HashMap::new();
         let length = polymers[0].monomers.len();
@@ -107,9 +89,140 @@ impl<M: Monomer> Polymer<M> {
     }
 }
 
+impl<M: Monomer> Deref for Polymer<M> {
+    type Target = PolymerSlice<M>;
+    fn deref(&self) -> &PolymerSlice<M> {
+        PolymerSlice::from_monomers(&self.monomers)
+    }
+}
+
+impl<M: Monomer> Borrow<PolymerSlice<M>> for Polymer<M> {
+    fn borrow(&self) -> &PolymerSlice<M> {
+        self
+    }
+}
+
+impl<M: Monomer> AsRef<PolymerSlice<M>> for Polymer<M> {
+    fn as_ref(&self) -> &PolymerSlice<M> {
+        self
+    }
+}
+
+impl<M: Monomer> FromIterator<M> for Polymer<M> {
+    fn from_iter<I: IntoIterator<Item = M>>(iter: I) -> Self {
+        let monomers = iter.into_iter().collect();
+        Polymer { monomers }
+    }
+}
+
+impl<M: Monomer> Extend<M> for Polymer<M> {
+    fn extend<I: IntoIterator<Item = M>>(&mut self, iter: I) {
+        self.monomers.extend(iter);
+    }
+}
+
+//--------------------------------- POLYMER SLICE ------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Ord, PartialOrd, Debug)]
+#[repr(transparent)]
+pub struct PolymerSlice<M: Monomer> {
+    pub(crate) monomers: [M],
+}
+
+impl<M: Monomer> PolymerSlice<M> {
+    pub(crate) fn from_monomers(monomers: &[M]) -> &PolymerSlice<M> {
+        // SAFETY: PolymerSlice is a transparent wrapper around [M].
+        unsafe { &*(monomers as *const [M] as *const PolymerSlice<M>) }
+    }
+    pub fn len(&self) -> usize {
+        self.monomers.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.monomers.is_empty()
+    }
+    pub fn get(&self, index: usize) -> Option<&M> {
+        self.monomers.get(index)
+    }
+    pub fn iter(&self) -> Iter<'_, M> {
+        self.monomers.iter()
+    }
+    pub fn split_at(&self, mid: usize) -> (&PolymerSlice<M>, &PolymerSlice<M>) {
+        let (left, right) = self.monomers.split_at(mid);
+        (
+            PolymerSlice::from_monomers(left),
+            PolymerSlice::from_monomers(right),
+        )
+    }
+    pub fn to_polymer(&self) -> Polymer<M> {
+        let monomers = self.monomers.to_vec();
+        Polymer { monomers }
+    }
+    pub fn monomer_count(&self) -> HashMap<M, usize> {
+        let mut count = HashMap::new();
+        for monomer in &self.monomers {
+            let prev = count.get(monomer);
+            let current = prev.unwrap_or(&0) + 1;
+            count.insert(*monomer, current);
+        }
+        count
+    }
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        self.monomers
+            .iter()
+            .zip(other.monomers.iter())
+            .filter(|p| p.0 != p.1)
+            .count()
+    }
+    pub fn motif_locations(&self, motif: &Self) -> Vec<usize> {
+        self.monomers
+            .windows(motif.monomers.len())
+            .enumerate()
+            .filter(|e| *e.1 == motif.monomers)
+            .map(|e| e.0 + 1)
+            .collect()
+    }
+}
+
+impl<M: Monomer, I: SliceIndex<[M], Output = [M]>> Index<I> for PolymerSlice<M> {
+    type Output = PolymerSlice<M>;
+    fn index(&self, index: I) -> &PolymerSlice<M> {
+        PolymerSlice::from_monomers(&self.monomers[index])
+    }
+}
+
+impl<M: Monomer> ToOwned for PolymerSlice<M> {
+    type Owned = Polymer<M>;
+    fn to_owned(&self) -> Polymer<M> {
+        self.to_polymer()
+    }
+}
+
+impl<'a, M: Monomer> IntoIterator for &'a PolymerSlice<M> {
+    type Item = &'a M;
+    type IntoIter = Iter<'a, M>;
+    fn into_iter(self) -> Iter<'a, M> {
+        self.monomers.iter()
+    }
+}
+
+impl<M: Monomer> fmt::Display for PolymerSlice<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for monomer in &self.monomers {
+            write!(f, "{}", monomer.symbol())?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: Monomer> fmt::Display for Polymer<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
 //---------------------------------- NUCLEIC ACID ------------------------------------//
 
-impl<N: Nucleotide> Polymer<N> {
+impl<N: Nucleotide> PolymerSlice<N> {
     pub fn gc_content(&self) -> f64 {
         let count = self.monomers.iter().filter(|n| n.is_gc()).count() as f64;
         let length = self.monomers.len() as f64;
@@ -145,7 +258,9 @@ impl<N: Nucleotide> Polymer<N> {
 
 pub type Dna = Polymer<DnaNucleotide>;
 
-impl Dna {
+pub type DnaSlice = PolymerSlice<DnaNucleotide>;
+
+impl DnaSlice {
     pub fn transcribe(&self) -> Rna {
         let monomers = self
             .monomers
@@ -160,7 +275,9 @@ impl Dna {
 
 pub type Rna = Polymer<RnaNucleotide>;
 
-impl Rna {
+pub type RnaSlice = PolymerSlice<RnaNucleotide>;
+
+impl RnaSlice {
     pub fn untranscribe(&self) -> Dna {
         let monomers = self
             .monomers
@@ -215,7 +332,9 @@ impl Rna {
 
 pub type Protein = Polymer<AminoAcid>;
 
-impl Protein {
+pub type ProteinSlice = PolymerSlice<AminoAcid>;
+
+impl ProteinSlice {
     pub fn mass(&self) -> f64 {
         self.monomers.iter().map(AminoAcid::mass).sum()
     }
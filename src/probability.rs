@@ -0,0 +1,33 @@
+use crate::monomers::Nucleotide;
+use crate::polymers::PolymerSlice;
+
+//--------------------------------- RANDOM STRINGS -----------------------------------//
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn log_probability(&self, gc_fraction: f64) -> f64 {
+        let gc = (gc_fraction / 2.0).log10();
+        let at = ((1.0 - gc_fraction) / 2.0).log10();
+        self.monomers
+            .iter()
+            .map(|n| if n.is_gc() { gc } else { at })
+            .sum()
+    }
+    pub fn expected_occurrences(&self, length: usize, gc_fraction: f64) -> f64 {
+        let positions = (length + 1).saturating_sub(self.monomers.len()) as f64;
+        positions * 10f64.powf(self.log_probability(gc_fraction))
+    }
+    pub fn log_expected_occurrences(&self, length: usize, gc_fraction: f64) -> f64 {
+        let positions = (length + 1).saturating_sub(self.monomers.len()) as f64;
+        positions.log10() + self.log_probability(gc_fraction)
+    }
+    pub fn log_probability_any_match(&self, count: usize, gc_fraction: f64) -> f64 {
+        let log_probability = self.log_probability(gc_fraction);
+        let probability = 10f64.powf(log_probability);
+        if probability > f64::MIN_POSITIVE {
+            let none = count as f64 * (-probability).ln_1p();
+            (-none.exp_m1()).log10()
+        } else {
+            (count as f64).log10() + log_probability
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::masses::MassType;
+use crate::monomers::AminoAcid;
+use crate::monomers::Monomer;
+use crate::polymers::Protein;
+use crate::polymers::ProteinSlice;
+use std::ops::RangeInclusive;
+
+//------------------------------------ PROTEASES -------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Cleavage {
+    CTerminal,
+    NTerminal,
+}
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Protease {
+    pub name: &'static str,
+    pub residues: &'static str,
+    pub cleavage: Cleavage,
+    pub proline_exception: bool,
+}
+
+pub const PROTEASES: &[Protease] = &[
+    Protease::new("Asp-N", "D", Cleavage::NTerminal, false),
+    Protease::new("Chymotrypsin", "FWY", Cleavage::CTerminal, true),
+    Protease::new("Glu-C", "E", Cleavage::CTerminal, true),
+    Protease::new("Lys-C", "K", Cleavage::CTerminal, false),
+    Protease::new("Trypsin", "KR", Cleavage::CTerminal, true),
+];
+
+impl Protease {
+    pub const fn new(
+        name: &'static str,
+        residues: &'static str,
+        cleavage: Cleavage,
+        proline_exception: bool,
+    ) -> Self {
+        Protease {
+            name,
+            residues,
+            cleavage,
+            proline_exception,
+        }
+    }
+    pub fn find(name: &str) -> Option<Protease> {
+        PROTEASES
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+    fn cleaves(&self, before: &AminoAcid, after: &AminoAcid) -> bool {
+        let target = match self.cleavage {
+            Cleavage::CTerminal => before,
+            Cleavage::NTerminal => after,
+        };
+        self.residues.contains(target.symbol())
+            && !(self.proline_exception && *after == AminoAcid::Proline)
+    }
+}
+
+//------------------------------------- DIGEST ---------------------------------------//
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Peptide {
+    pub start: usize,
+    pub end: usize,
+    pub protein: Protein,
+    pub mass: f64,
+    pub missed_cleavages: usize,
+}
+
+impl ProteinSlice {
+    pub fn cleavage_sites(&self, proteases: &[Protease]) -> Vec<usize> {
+        (1..self.monomers.len())
+            .filter(|i| {
+                let (before, after) = (&self.monomers[i - 1], &self.monomers[*i]);
+                proteases.iter().any(|p| p.cleaves(before, after))
+            })
+            .collect()
+    }
+    pub fn digest(
+        &self,
+        proteases: &[Protease],
+        missed_cleavages: usize,
+        lengths: RangeInclusive<usize>,
+    ) -> Vec<Peptide> {
+        if self.monomers.is_empty() {
+            return Vec::new();
+        }
+        let mut boundaries = vec![0];
+        boundaries.extend(self.cleavage_sites(proteases));
+        boundaries.push(self.monomers.len());
+        let mut peptides = Vec::new();
+        for i in 0..boundaries.len() - 1 {
+            for missed in 0..=missed_cleavages {
+                let Some(end) = boundaries.get(i + 1 + missed) else {
+                    break;
+                };
+                let start = boundaries[i];
+                if !lengths.contains(&(end - start)) {
+                    continue;
+                }
+                let protein = self[start..*end].to_polymer();
+                peptides.push(Peptide {
+                    start: start + 1,
+                    end: *end,
+                    mass: protein.mass() + MassType::Monoisotopic.water(),
+                    protein,
+                    missed_cleavages: missed,
+                });
+            }
+        }
+        peptides
+    }
+}
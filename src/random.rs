@@ -0,0 +1,149 @@
+use crate::monomers::Monomer;
+use crate::monomers::Nucleotide;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use std::collections::HashMap;
+
+//-------------------------------------- RNG -----------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        let mut state = [0; 4];
+        for word in &mut state {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut x = seed;
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *word = x ^ (x >> 31);
+        }
+        Rng { state }
+    }
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+    fn weighted<T: Copy>(&mut self, choices: &[(T, f64)]) -> T {
+        let total = choices.iter().map(|c| c.1).sum::<f64>();
+        let mut target = self.next_f64() * total;
+        for (choice, weight) in choices {
+            if target < *weight {
+                return *choice;
+            }
+            target -= weight;
+        }
+        choices[choices.len() - 1].0
+    }
+}
+
+//-------------------------------- RANDOM POLYMERS -----------------------------------//
+
+impl<M: Monomer> Polymer<M> {
+    pub fn random(length: usize, rng: &mut Rng) -> Self {
+        let monomers = (0..length)
+            .map(|_| M::ALPHABET[rng.below(M::ALPHABET.len())])
+            .collect();
+        Polymer { monomers }
+    }
+    pub fn random_with_composition(
+        length: usize,
+        composition: &HashMap<M, f64>,
+        rng: &mut Rng,
+    ) -> Option<Self> {
+        let mut choices = composition
+            .iter()
+            .filter(|e| *e.1 > 0.0)
+            .map(|e| (*e.0, *e.1))
+            .collect::<Vec<(M, f64)>>();
+        if choices.is_empty() || choices.iter().any(|c| !c.1.is_finite()) {
+            return None;
+        }
+        choices.sort_by_key(|c| c.0);
+        let monomers = (0..length).map(|_| rng.weighted(&choices)).collect();
+        Some(Polymer { monomers })
+    }
+}
+
+impl<N: Nucleotide> Polymer<N> {
+    pub fn random_with_gc(length: usize, gc_fraction: f64, rng: &mut Rng) -> Option<Self> {
+        if !(0.0..=1.0).contains(&gc_fraction) {
+            return None;
+        }
+        let composition = N::ALPHABET
+            .iter()
+            .map(|n| {
+                let weight = if n.is_gc() {
+                    gc_fraction
+                } else {
+                    1.0 - gc_fraction
+                };
+                (*n, weight / 2.0)
+            })
+            .collect();
+        Polymer::random_with_composition(length, &composition, rng)
+    }
+}
+
+//------------------------------------ MUTATION --------------------------------------//
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum SubstitutionModel {
+    JukesCantor,
+    Kimura { transition_transversion_ratio: f64 },
+}
+
+impl SubstitutionModel {
+    fn substitute<N: Nucleotide>(&self, nucleotide: N, rng: &mut Rng) -> N {
+        let choices = N::ALPHABET
+            .iter()
+            .filter(|n| **n != nucleotide)
+            .map(|n| {
+                let transition = n.is_purine() == nucleotide.is_purine();
+                let weight = match self {
+                    SubstitutionModel::JukesCantor => 1.0,
+                    SubstitutionModel::Kimura {
+                        transition_transversion_ratio,
+                    } if transition => 2.0 * transition_transversion_ratio,
+                    SubstitutionModel::Kimura { .. } => 1.0,
+                };
+                (*n, weight)
+            })
+            .collect::<Vec<(N, f64)>>();
+        rng.weighted(&choices)
+    }
+}
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn mutate(&self, rate: f64, model: SubstitutionModel, rng: &mut Rng) -> Polymer<N> {
+        let monomers = self
+            .monomers
+            .iter()
+            .map(|n| {
+                if rng.next_f64() < rate {
+                    model.substitute(*n, rng)
+                } else {
+                    *n
+                }
+            })
+            .collect();
+        Polymer { monomers }
+    }
+}
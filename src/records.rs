@@ -0,0 +1,78 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+
+//------------------------------------- RECORD ---------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd, Debug)]
+pub struct Record<M: Monomer> {
+    pub id: String,
+    pub polymer: Polymer<M>,
+}
+
+impl<M: Monomer> Record<M> {
+    pub fn new(id: &str, polymer: Polymer<M>) -> Self {
+        Record {
+            id: id.to_string(),
+            polymer,
+        }
+    }
+}
+
+//------------------------------------- FASTA ----------------------------------------//
+
+impl<M: Monomer> Record<M> {
+    pub fn from_fasta(fasta: &str, monomer: fn(char) -> Option<M>) -> Option<Vec<Self>> {
+        let mut records = Vec::new();
+        let mut id: Option<&str> = None;
+        let mut symbols = String::new();
+        for line in fasta.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some(header) = line.strip_prefix('>') {
+                if let Some(id) = id {
+                    records.push(Record::new(id, Polymer::new(&symbols, monomer)?));
+                }
+                id = Some(header.trim());
+                symbols.clear();
+            } else {
+                id?;
+                symbols.push_str(line);
+            }
+        }
+        if let Some(id) = id {
+            records.push(Record::new(id, Polymer::new(&symbols, monomer)?));
+        }
+        Some(records)
+    }
+    pub fn to_fasta(records: &[Self]) -> String {
+        records
+            .iter()
+            .map(|r| format!(">{}\n{}\n", r.id, r.polymer))
+            .collect()
+    }
+}
+
+//------------------------------------- FASTQ ----------------------------------------//
+
+impl<M: Monomer> Record<M> {
+    pub fn from_fastq(fastq: &str, monomer: fn(char) -> Option<M>) -> Option<Vec<Self>> {
+        let lines = fastq
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<&str>>();
+        if lines.len() % 4 != 0 {
+            return None;
+        }
+        lines
+            .chunks(4)
+            .map(|entry| {
+                let id = entry[0].strip_prefix('@')?;
+                let polymer = Polymer::new(entry[1], monomer)?;
+                entry[2].strip_prefix('+')?;
+                if entry[3].chars().count() != polymer.len() {
+                    return None;
+                }
+                Some(Record::new(id.trim(), polymer))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,176 @@
+use crate::circular::CircularPolymer;
+use crate::monomers::DnaNucleotide;
+use crate::monomers::Monomer;
+use crate::polymers::Dna;
+use crate::polymers::DnaSlice;
+use crate::polymers::Polymer;
+
+//------------------------------ REVERSE PALINDROMES ---------------------------------//
+
+impl DnaSlice {
+    pub fn reverse_palindromes(&self, min_length: usize, max_length: usize) -> Vec<(usize, usize)> {
+        let mut palindromes = Vec::new();
+        for start in 0..self.monomers.len() {
+            for length in min_length.max(1)..=max_length {
+                if start + length > self.monomers.len() {
+                    break;
+                }
+                let candidate = &self[start..start + length];
+                if candidate.reverse_complement().monomers == candidate.monomers {
+                    palindromes.push((start + 1, length));
+                }
+            }
+        }
+        palindromes
+    }
+}
+
+//------------------------------ RESTRICTION ENZYMES ---------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Overhang {
+    Blunt,
+    FivePrime,
+    ThreePrime,
+}
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct Enzyme {
+    pub name: &'static str,
+    pub site: &'static str,
+    pub cut: usize,
+    pub complement_cut: usize,
+}
+
+pub const ENZYMES: &[Enzyme] = &[
+    Enzyme::new("BamHI", "GGATCC", 1, 5),
+    Enzyme::new("EcoRI", "GAATTC", 1, 5),
+    Enzyme::new("EcoRV", "GATATC", 3, 3),
+    Enzyme::new("HindIII", "AAGCTT", 1, 5),
+    Enzyme::new("KpnI", "GGTACC", 5, 1),
+    Enzyme::new("NdeI", "CATATG", 2, 4),
+    Enzyme::new("NotI", "GCGGCCGC", 2, 6),
+    Enzyme::new("PstI", "CTGCAG", 5, 1),
+    Enzyme::new("SacI", "GAGCTC", 5, 1),
+    Enzyme::new("SalI", "GTCGAC", 1, 5),
+    Enzyme::new("SmaI", "CCCGGG", 3, 3),
+    Enzyme::new("XbaI", "TCTAGA", 1, 5),
+    Enzyme::new("XhoI", "CTCGAG", 1, 5),
+];
+
+impl Enzyme {
+    pub const fn new(
+        name: &'static str,
+        site: &'static str,
+        cut: usize,
+        complement_cut: usize,
+    ) -> Self {
+        Enzyme {
+            name,
+            site,
+            cut,
+            complement_cut,
+        }
+    }
+    pub fn find(name: &str) -> Option<Enzyme> {
+        ENZYMES
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+            .copied()
+    }
+    pub fn recognition_site(&self) -> Option<Dna> {
+        Polymer::new(self.site, DnaNucleotide::new)
+    }
+    pub fn overhang(&self) -> Overhang {
+        match self.cut.cmp(&self.complement_cut) {
+            std::cmp::Ordering::Equal => Overhang::Blunt,
+            std::cmp::Ordering::Less => Overhang::FivePrime,
+            std::cmp::Ordering::Greater => Overhang::ThreePrime,
+        }
+    }
+}
+
+//------------------------------------- DIGEST ---------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Fragment {
+    pub start: usize,
+    pub end: usize,
+    pub dna: Dna,
+}
+
+fn cuts(sites: impl Iterator<Item = (usize, Enzyme)>, wrap: Option<usize>) -> Vec<usize> {
+    let mut cuts = sites
+        .map(|(location, enzyme)| location - 1 + enzyme.cut)
+        .map(|cut| wrap.map_or(cut, |length| cut % length))
+        .collect::<Vec<usize>>();
+    cuts.sort_unstable();
+    cuts.dedup();
+    cuts
+}
+
+impl DnaSlice {
+    pub fn digest(&self, enzymes: &[Enzyme]) -> Vec<Fragment> {
+        let length = self.monomers.len();
+        let sites = enzymes.iter().flat_map(|enzyme| {
+            let site = enzyme.recognition_site();
+            site.map(|s| self.motif_locations(&s))
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |location| (location, *enzyme))
+        });
+        let mut boundaries = vec![0];
+        boundaries.extend(
+            cuts(sites, None)
+                .into_iter()
+                .filter(|cut| *cut > 0 && *cut < length),
+        );
+        boundaries.push(length);
+        boundaries
+            .windows(2)
+            .map(|w| Fragment {
+                start: w[0] + 1,
+                end: w[1],
+                dna: self[w[0]..w[1]].to_polymer(),
+            })
+            .collect()
+    }
+}
+
+impl CircularPolymer<DnaNucleotide> {
+    pub fn digest(&self, enzymes: &[Enzyme]) -> Vec<Fragment> {
+        let length = self.len();
+        let sites = enzymes.iter().flat_map(|enzyme| {
+            let site = enzyme.recognition_site();
+            site.map(|s| self.motif_locations(&s))
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |location| (location, *enzyme))
+        });
+        let cuts = cuts(sites, Some(length));
+        if cuts.is_empty() {
+            let dna = self.as_linear().clone();
+            return vec![Fragment {
+                start: 1,
+                end: length,
+                dna,
+            }];
+        }
+        let linear = self.linearize(length);
+        (0..cuts.len())
+            .map(|i| {
+                let from = cuts[i];
+                let to = if i + 1 < cuts.len() {
+                    cuts[i + 1]
+                } else {
+                    cuts[0] + length
+                };
+                Fragment {
+                    start: from + 1,
+                    end: (to - 1) % length + 1,
+                    dna: linear[from..to].to_polymer(),
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,167 @@
+use crate::monomers::Nucleotide;
+use crate::polymers::PolymerSlice;
+use std::collections::VecDeque;
+
+//-------------------------------------- SEED ----------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Ord, PartialOrd, Debug)]
+pub struct Seed {
+    pub position: usize,
+    pub hash: u64,
+}
+
+fn hash(code: u64) -> u64 {
+    let mut x = code;
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    fn seeds(&self, k: usize) -> Option<Vec<Seed>> {
+        let seeds = self
+            .encoded_kmers(k)?
+            .map(|kmer| Seed {
+                position: kmer.position,
+                hash: hash(kmer.canonical()),
+            })
+            .collect();
+        Some(seeds)
+    }
+}
+
+//----------------------------------- MINIMIZERS -------------------------------------//
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn minimizers(&self, w: usize, k: usize) -> Option<Vec<Seed>> {
+        if w == 0 {
+            return None;
+        }
+        let seeds = self.seeds(k)?;
+        let mut minimizers: Vec<Seed> = Vec::new();
+        let mut window: VecDeque<Seed> = VecDeque::new();
+        for (i, seed) in seeds.iter().enumerate() {
+            while window.back().is_some_and(|s| s.hash > seed.hash) {
+                window.pop_back();
+            }
+            window.push_back(*seed);
+            while window
+                .front()
+                .is_some_and(|s| s.position + w <= seed.position)
+            {
+                window.pop_front();
+            }
+            if i + 1 >= w {
+                let minimizer = window[0];
+                if minimizers.last() != Some(&minimizer) {
+                    minimizers.push(minimizer);
+                }
+            }
+        }
+        Some(minimizers)
+    }
+}
+
+//------------------------------------ SYNCMERS --------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Syncmer {
+    Open,
+    Closed,
+}
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn syncmers(&self, k: usize, s: usize, syncmer: Syncmer) -> Option<Vec<Seed>> {
+        if s == 0 || s > k {
+            return None;
+        }
+        let smers = self.seeds(s)?;
+        let kmers = self.seeds(k)?;
+        let span = k - s + 1;
+        let syncmers = kmers
+            .into_iter()
+            .filter(|kmer| {
+                let window = &smers[kmer.position - 1..kmer.position - 1 + span];
+                let offset = window
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|e| e.1.hash)
+                    .unwrap()
+                    .0;
+                match syncmer {
+                    Syncmer::Open => offset == 0,
+                    Syncmer::Closed => offset == 0 || offset == span - 1,
+                }
+            })
+            .collect();
+        Some(syncmers)
+    }
+}
+
+//------------------------------------- MINHASH --------------------------------------//
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MinHash {
+    k: usize,
+    size: usize,
+    hashes: Vec<u64>,
+}
+
+impl MinHash {
+    pub fn new<N: Nucleotide>(polymer: &PolymerSlice<N>, k: usize, size: usize) -> Option<Self> {
+        if size == 0 {
+            return None;
+        }
+        let mut hashes = polymer
+            .seeds(k)?
+            .iter()
+            .map(|s| s.hash)
+            .collect::<Vec<u64>>();
+        hashes.sort_unstable();
+        hashes.dedup();
+        hashes.truncate(size);
+        Some(MinHash { k, size, hashes })
+    }
+    pub fn hashes(&self) -> &[u64] {
+        &self.hashes
+    }
+    pub fn jaccard(&self, other: &Self) -> f64 {
+        let size = self.size.min(other.size);
+        let mut union = self.hashes.clone();
+        union.extend(&other.hashes);
+        union.sort_unstable();
+        union.dedup();
+        union.truncate(size);
+        if union.is_empty() {
+            return 0.0;
+        }
+        let shared = union
+            .iter()
+            .filter(|h| self.hashes.binary_search(h).is_ok())
+            .filter(|h| other.hashes.binary_search(h).is_ok())
+            .count();
+        shared as f64 / union.len() as f64
+    }
+    pub fn mash_distance(&self, other: &Self) -> f64 {
+        let jaccard = self.jaccard(other);
+        if jaccard == 0.0 {
+            1.0
+        } else {
+            let distance = -(2.0 * jaccard / (1.0 + jaccard)).ln() / self.k as f64;
+            distance.min(1.0)
+        }
+    }
+}
+
+impl<N: Nucleotide> PolymerSlice<N> {
+    pub fn jaccard(&self, other: &Self, k: usize, size: usize) -> Option<f64> {
+        let sketch = MinHash::new(self, k, size)?;
+        let other = MinHash::new(other, k, size)?;
+        Some(sketch.jaccard(&other))
+    }
+    pub fn mash_distance(&self, other: &Self, k: usize, size: usize) -> Option<f64> {
+        let sketch = MinHash::new(self, k, size)?;
+        let other = MinHash::new(other, k, size)?;
+        Some(sketch.mash_distance(&other))
+    }
+}
@@ -0,0 +1,301 @@
+use crate::monomers::AminoAcid;
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::polymers::Protein;
+use crate::polymers::ProteinSlice;
+use std::collections::BTreeSet;
+
+//------------------------------------ RESIDUES --------------------------------------//
+
+fn residues(mass: f64, tolerance: f64) -> Vec<AminoAcid> {
+    AminoAcid::ALPHABET
+        .iter()
+        .filter(|a| (a.mass() - mass).abs() <= tolerance)
+        .copied()
+        .collect()
+}
+
+//----------------------------------- CANDIDATES -------------------------------------//
+
+#[derive(PartialEq, Eq, Hash, Clone, Ord, PartialOrd, Debug)]
+pub struct Candidates {
+    alternatives: Vec<Vec<AminoAcid>>,
+}
+
+impl Candidates {
+    pub fn alternatives(&self) -> &[Vec<AminoAcid>] {
+        &self.alternatives
+    }
+    pub fn len(&self) -> usize {
+        self.alternatives.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.alternatives.is_empty()
+    }
+    pub fn count(&self) -> Option<usize> {
+        self.alternatives
+            .iter()
+            .try_fold(1usize, |count, a| count.checked_mul(a.len()))
+    }
+    pub fn contains(&self, protein: &ProteinSlice) -> bool {
+        protein.monomers.len() == self.alternatives.len()
+            && protein
+                .monomers
+                .iter()
+                .zip(&self.alternatives)
+                .all(|(m, a)| a.contains(m))
+    }
+    pub fn proteins(&self) -> Proteins<'_> {
+        Proteins {
+            alternatives: &self.alternatives,
+            indices: vec![0; self.alternatives.len()],
+            done: self.alternatives.iter().any(Vec::is_empty),
+        }
+    }
+}
+
+pub struct Proteins<'a> {
+    alternatives: &'a [Vec<AminoAcid>],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl Iterator for Proteins<'_> {
+    type Item = Protein;
+    fn next(&mut self) -> Option<Protein> {
+        if self.done {
+            return None;
+        }
+        let monomers = self
+            .alternatives
+            .iter()
+            .zip(&self.indices)
+            .map(|(a, i)| a[*i])
+            .collect();
+        self.done = true;
+        for (index, alternatives) in self.indices.iter_mut().zip(self.alternatives).rev() {
+            *index += 1;
+            if *index < alternatives.len() {
+                self.done = false;
+                break;
+            }
+            *index = 0;
+        }
+        Some(Polymer { monomers })
+    }
+}
+
+//------------------------------- SPECTRUM INFERENCE ---------------------------------//
+
+impl Protein {
+    pub fn from_prefix_spectrum(spectrum: &[f64], tolerance: f64) -> Option<Candidates> {
+        if spectrum.len() < 2 {
+            return None;
+        }
+        let alternatives = spectrum
+            .windows(2)
+            .map(|w| Some(residues(w[1] - w[0], tolerance)).filter(|r| !r.is_empty()))
+            .collect::<Option<Vec<Vec<AminoAcid>>>>()?;
+        Some(Candidates { alternatives })
+    }
+    pub fn from_ion_spectrum(parent_mass: f64, ions: &[f64], tolerance: f64) -> Vec<Candidates> {
+        if ions.len() < 4 {
+            return Vec::new();
+        }
+        let graph = SpectrumGraph::new(ions, tolerance);
+        let complementary = |a: usize, b: usize| {
+            (graph.masses[a] + graph.masses[b] - parent_mass).abs() <= tolerance
+        };
+        let mut candidates = BTreeSet::new();
+        let length = (ions.len() - 2) / 2;
+        for start in 0..graph.masses.len() {
+            graph.paths(
+                &mut vec![start],
+                &mut Vec::new(),
+                length,
+                &mut |nodes, alternatives| {
+                    let exclusive = nodes
+                        .iter()
+                        .enumerate()
+                        .all(|(i, a)| nodes[i + 1..].iter().all(|b| !complementary(*a, *b)));
+                    if exclusive {
+                        candidates.insert(Candidates {
+                            alternatives: alternatives.to_vec(),
+                        });
+                    }
+                },
+            );
+        }
+        candidates.into_iter().collect()
+    }
+}
+
+//--------------------------------- SPECTRUM GRAPH -----------------------------------//
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct SpectrumGraph {
+    masses: Vec<f64>,
+    edges: Vec<Vec<(usize, Vec<AminoAcid>)>>,
+    longest: Vec<usize>,
+}
+
+impl SpectrumGraph {
+    pub fn new(spectrum: &[f64], tolerance: f64) -> Self {
+        let mut masses = spectrum.to_vec();
+        masses.sort_by(f64::total_cmp);
+        let edges = (0..masses.len())
+            .map(|i| {
+                (i + 1..masses.len())
+                    .map(|j| (j, residues(masses[j] - masses[i], tolerance)))
+                    .filter(|(_, residues)| !residues.is_empty())
+                    .collect()
+            })
+            .collect::<Vec<Vec<(usize, Vec<AminoAcid>)>>>();
+        let mut longest = vec![0; masses.len()];
+        for i in (0..masses.len()).rev() {
+            longest[i] = edges[i]
+                .iter()
+                .map(|(j, _)| longest[*j] + 1)
+                .max()
+                .unwrap_or(0);
+        }
+        SpectrumGraph {
+            masses,
+            edges,
+            longest,
+        }
+    }
+    pub fn masses(&self) -> &[f64] {
+        &self.masses
+    }
+    pub fn adjacency_list(&self) -> Vec<(f64, f64, AminoAcid)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(|(i, targets)| {
+                targets.iter().flat_map(move |(j, residues)| {
+                    residues
+                        .iter()
+                        .map(move |r| (self.masses[i], self.masses[*j], *r))
+                })
+            })
+            .collect()
+    }
+    fn paths<F>(
+        &self,
+        nodes: &mut Vec<usize>,
+        alternatives: &mut Vec<Vec<AminoAcid>>,
+        remaining: usize,
+        visit: &mut F,
+    ) where
+        F: FnMut(&[usize], &[Vec<AminoAcid>]),
+    {
+        if remaining == 0 {
+            visit(nodes, alternatives);
+            return;
+        }
+        let node = *nodes.last().unwrap();
+        for (j, residues) in &self.edges[node] {
+            if self.longest[*j] + 1 < remaining {
+                continue;
+            }
+            nodes.push(*j);
+            alternatives.push(residues.clone());
+            self.paths(nodes, alternatives, remaining - 1, visit);
+            nodes.pop();
+            alternatives.pop();
+        }
+    }
+    pub fn longest_proteins(&self) -> Vec<Candidates> {
+        let Some(best) = self.longest.iter().max().copied().filter(|l| *l > 0) else {
+            return Vec::new();
+        };
+        let mut candidates = BTreeSet::new();
+        for start in (0..self.masses.len()).filter(|i| self.longest[*i] == best) {
+            self.paths(
+                &mut vec![start],
+                &mut Vec::new(),
+                best,
+                &mut |_, alternatives| {
+                    candidates.insert(Candidates {
+                        alternatives: alternatives.to_vec(),
+                    });
+                },
+            );
+        }
+        candidates.into_iter().collect()
+    }
+}
+
+//------------------------------ SPECTRAL CONVOLUTION --------------------------------//
+
+pub fn spectral_convolution(first: &[f64], second: &[f64], tolerance: f64) -> Vec<(f64, usize)> {
+    let mut differences = first
+        .iter()
+        .flat_map(|a| second.iter().map(move |b| a - b))
+        .collect::<Vec<f64>>();
+    differences.sort_by(f64::total_cmp);
+    let mut convolution: Vec<(f64, usize)> = Vec::new();
+    let mut anchor = f64::NEG_INFINITY;
+    for difference in differences {
+        match convolution.last_mut() {
+            Some((mass, count)) if difference - anchor <= tolerance => {
+                *mass += (difference - *mass) / (*count + 1) as f64;
+                *count += 1;
+            }
+            _ => {
+                anchor = difference;
+                convolution.push((difference, 1));
+            }
+        }
+    }
+    convolution.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.total_cmp(&b.0)));
+    convolution
+}
+
+//--------------------------------- PROTEIN MATCHING ---------------------------------//
+
+impl ProteinSlice {
+    pub fn complete_spectrum(&self) -> Vec<f64> {
+        let total = self.mass();
+        let mut prefix = 0.0;
+        let mut spectrum = Vec::with_capacity(2 * self.monomers.len());
+        for aminoacid in self
+            .monomers
+            .iter()
+            .take(self.monomers.len().saturating_sub(1))
+        {
+            prefix += aminoacid.mass();
+            spectrum.push(prefix);
+            spectrum.push(total - prefix);
+        }
+        spectrum.sort_by(f64::total_cmp);
+        spectrum
+    }
+    pub fn spectrum_score(&self, spectrum: &[f64], tolerance: f64) -> usize {
+        spectral_convolution(&self.complete_spectrum(), spectrum, tolerance)
+            .first()
+            .map_or(0, |c| c.1)
+    }
+}
+
+impl Protein {
+    pub fn best_matches<'a>(
+        candidates: &'a [Protein],
+        spectrum: &[f64],
+        tolerance: f64,
+    ) -> (usize, Vec<&'a Protein>) {
+        let scores = candidates
+            .iter()
+            .map(|c| c.spectrum_score(spectrum, tolerance))
+            .collect::<Vec<usize>>();
+        let best = scores.iter().max().copied().unwrap_or(0);
+        let matches = candidates
+            .iter()
+            .zip(scores)
+            .filter(|(_, score)| *score == best)
+            .map(|(candidate, _)| candidate)
+            .collect();
+        (best, matches)
+    }
+}
@@ -0,0 +1,98 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::trees::Tree;
+use std::collections::HashSet;
+
+//-------------------------------- CHARACTER TABLE -----------------------------------//
+
+impl<M: Monomer> Polymer<M> {
+    pub fn character_table(polymers: &[Polymer<M>]) -> Vec<Vec<bool>> {
+        let mut characters = Vec::new();
+        let length = polymers.iter().map(|p| p.monomers.len()).min().unwrap_or(0);
+        for i in 0..length {
+            let reference = polymers[0].monomers[i];
+            let column = polymers
+                .iter()
+                .map(|p| p.monomers[i] == reference)
+                .collect::<Vec<bool>>();
+            let other = polymers
+                .iter()
+                .map(|p| p.monomers[i])
+                .find(|m| *m != reference);
+            let biallelic = other.is_some_and(|other| {
+                polymers
+                    .iter()
+                    .all(|p| p.monomers[i] == reference || p.monomers[i] == other)
+            });
+            let matching = column.iter().filter(|c| **c).count();
+            if biallelic && matching >= 2 && polymers.len() - matching >= 2 {
+                characters.push(column);
+            }
+        }
+        characters
+    }
+}
+
+//------------------------------------- SPLITS ---------------------------------------//
+
+fn canonical(split: &[bool]) -> Vec<bool> {
+    if split[0] {
+        split.iter().map(|s| !s).collect()
+    } else {
+        split.to_vec()
+    }
+}
+
+impl Tree {
+    pub fn taxa(&self) -> Vec<&str> {
+        let mut taxa = self.leaves();
+        taxa.sort_unstable();
+        taxa
+    }
+    pub fn splits(&self) -> Vec<Vec<bool>> {
+        let taxa = self.taxa();
+        let mut below = vec![vec![false; taxa.len()]; self.len()];
+        let mut splits = Vec::new();
+        let mut seen = HashSet::new();
+        for id in self.preorder().into_iter().rev() {
+            let node = self.node(id).unwrap();
+            if node.is_leaf() {
+                if let Some(index) = node.label().and_then(|l| taxa.binary_search(&l).ok()) {
+                    below[id][index] = true;
+                }
+                continue;
+            }
+            for child in node.children() {
+                let leaves = below[*child].clone();
+                for (taxon, leaf) in below[id].iter_mut().zip(leaves) {
+                    *taxon |= leaf;
+                }
+            }
+            let count = below[id].iter().filter(|b| **b).count();
+            if id != self.root()
+                && count >= 2
+                && taxa.len() - count >= 2
+                && seen.insert(canonical(&below[id]))
+            {
+                splits.push(below[id].clone());
+            }
+        }
+        splits
+    }
+    pub fn split_distance(&self, other: &Tree) -> Option<usize> {
+        if self.taxa() != other.taxa() {
+            return None;
+        }
+        let splits = self
+            .splits()
+            .iter()
+            .map(|s| canonical(s))
+            .collect::<HashSet<Vec<bool>>>();
+        let other = other
+            .splits()
+            .iter()
+            .map(|s| canonical(s))
+            .collect::<HashSet<Vec<bool>>>();
+        Some(splits.symmetric_difference(&other).count())
+    }
+}
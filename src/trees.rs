@@ -0,0 +1,497 @@
+//-------------------------------------- TREE ----------------------------------------//
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Node {
+    label: Option<String>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    length: Option<f64>,
+}
+
+impl Node {
+    fn new(label: Option<String>) -> Self {
+        Node {
+            label,
+            parent: None,
+            children: Vec::new(),
+            length: None,
+        }
+    }
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+    pub fn parent(&self) -> Option<usize> {
+        self.parent
+    }
+    pub fn children(&self) -> &[usize] {
+        &self.children
+    }
+    pub fn length(&self) -> Option<f64> {
+        self.length
+    }
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Tree {
+    nodes: Vec<Node>,
+    root: usize,
+    rooted: bool,
+}
+
+impl Tree {
+    fn empty(rooted: bool) -> Self {
+        Tree {
+            nodes: Vec::new(),
+            root: 0,
+            rooted,
+        }
+    }
+    fn add_node(&mut self, label: Option<String>) -> usize {
+        self.nodes.push(Node::new(label));
+        self.nodes.len() - 1
+    }
+    fn attach(&mut self, parent: usize, child: usize, length: Option<f64>) {
+        self.nodes[parent].children.push(child);
+        self.nodes[child].parent = Some(parent);
+        self.nodes[child].length = length;
+    }
+    pub fn root(&self) -> usize {
+        self.root
+    }
+    pub fn node(&self, id: usize) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    pub fn is_rooted(&self) -> bool {
+        self.rooted
+    }
+    pub(crate) fn preorder(&self) -> Vec<usize> {
+        let mut order = Vec::new();
+        let mut stack = vec![self.root];
+        while let Some(id) = stack.pop() {
+            order.push(id);
+            stack.extend(self.nodes[id].children.iter().rev());
+        }
+        order
+    }
+}
+
+fn is_square(labels: &[&str], distances: &[Vec<f64>]) -> bool {
+    !labels.is_empty()
+        && distances.len() == labels.len()
+        && distances.iter().all(|row| row.len() == labels.len())
+}
+
+fn closest_pair(size: usize, score: impl Fn(usize, usize) -> f64) -> (usize, usize) {
+    let mut closest = (0, 1);
+    for i in 0..size {
+        for j in i + 1..size {
+            if score(i, j) < score(closest.0, closest.1) {
+                closest = (i, j);
+            }
+        }
+    }
+    closest
+}
+
+fn reduce(matrix: &[Vec<f64>], i: usize, j: usize, row: Vec<f64>) -> Vec<Vec<f64>> {
+    let kept = (0..matrix.len()).filter(|k| *k != i && *k != j);
+    let mut reduced = kept
+        .clone()
+        .map(|a| kept.clone().map(|b| matrix[a][b]).collect::<Vec<f64>>())
+        .collect::<Vec<Vec<f64>>>();
+    for (reduced_row, distance) in reduced.iter_mut().zip(row.iter()) {
+        reduced_row.push(*distance);
+    }
+    let mut last = row;
+    last.push(0.0);
+    reduced.push(last);
+    reduced
+}
+
+//------------------------------------- UPGMA ----------------------------------------//
+
+impl Tree {
+    pub fn upgma(labels: &[&str], distances: &[Vec<f64>]) -> Option<Tree> {
+        if !is_square(labels, distances) {
+            return None;
+        }
+        let mut tree = Tree::empty(true);
+        let mut clusters = labels
+            .iter()
+            .map(|label| (tree.add_node(Some(label.to_string())), 1, 0.0))
+            .collect::<Vec<(usize, usize, f64)>>();
+        let mut matrix = distances.to_vec();
+        while clusters.len() > 1 {
+            let (i, j) = closest_pair(clusters.len(), |a, b| matrix[a][b]);
+            let (first, first_size, first_height) = clusters[i];
+            let (second, second_size, second_height) = clusters[j];
+            let height = matrix[i][j] / 2.0;
+            let node = tree.add_node(None);
+            tree.attach(node, first, Some(height - first_height));
+            tree.attach(node, second, Some(height - second_height));
+            let size = first_size + second_size;
+            let row = (0..clusters.len())
+                .filter(|k| *k != i && *k != j)
+                .map(|k| {
+                    (matrix[i][k] * first_size as f64 + matrix[j][k] * second_size as f64)
+                        / size as f64
+                })
+                .collect();
+            matrix = reduce(&matrix, i, j, row);
+            clusters.remove(j);
+            clusters.remove(i);
+            clusters.push((node, size, height));
+        }
+        tree.root = clusters[0].0;
+        Some(tree)
+    }
+}
+
+//-------------------------------- NEIGHBOR JOINING ----------------------------------//
+
+impl Tree {
+    pub fn neighbor_joining(labels: &[&str], distances: &[Vec<f64>]) -> Option<Tree> {
+        if !is_square(labels, distances) {
+            return None;
+        }
+        let mut tree = Tree::empty(false);
+        let mut clusters = labels
+            .iter()
+            .map(|label| tree.add_node(Some(label.to_string())))
+            .collect::<Vec<usize>>();
+        let mut matrix = distances.to_vec();
+        while clusters.len() > 2 {
+            let size = clusters.len();
+            let totals = matrix
+                .iter()
+                .map(|row| row.iter().sum::<f64>())
+                .collect::<Vec<f64>>();
+            let (i, j) = closest_pair(size, |a, b| {
+                (size - 2) as f64 * matrix[a][b] - totals[a] - totals[b]
+            });
+            let first_length =
+                matrix[i][j] / 2.0 + (totals[i] - totals[j]) / (2.0 * (size - 2) as f64);
+            let second_length = matrix[i][j] - first_length;
+            let node = tree.add_node(None);
+            tree.attach(node, clusters[i], Some(first_length));
+            tree.attach(node, clusters[j], Some(second_length));
+            let row = (0..size)
+                .filter(|k| *k != i && *k != j)
+                .map(|k| (matrix[i][k] + matrix[j][k] - matrix[i][j]) / 2.0)
+                .collect();
+            matrix = reduce(&matrix, i, j, row);
+            clusters.remove(j);
+            clusters.remove(i);
+            clusters.push(node);
+        }
+        if clusters.len() == 2 && tree.nodes[clusters[1]].is_leaf() {
+            let node = tree.add_node(None);
+            tree.attach(node, clusters[0], Some(matrix[0][1] / 2.0));
+            tree.attach(node, clusters[1], Some(matrix[0][1] / 2.0));
+            clusters = vec![node];
+        } else if clusters.len() == 2 {
+            tree.attach(clusters[1], clusters[0], Some(matrix[0][1]));
+        }
+        tree.root = *clusters.last().unwrap();
+        Some(tree)
+    }
+}
+
+//------------------------------------- NEWICK ---------------------------------------//
+
+fn quote(label: &str) -> String {
+    let special = |c: char| c.is_whitespace() || "()[]':;,".contains(c);
+    if label.chars().any(special) {
+        format!("'{}'", label.replace('\'', "''"))
+    } else {
+        label.to_string()
+    }
+}
+
+impl Tree {
+    fn write_newick(&self, id: usize, newick: &mut String) {
+        let node = &self.nodes[id];
+        if !node.children.is_empty() {
+            newick.push('(');
+            for (i, child) in node.children.iter().enumerate() {
+                if i > 0 {
+                    newick.push(',');
+                }
+                self.write_newick(*child, newick);
+            }
+            newick.push(')');
+        }
+        if let Some(label) = &node.label {
+            newick.push_str(&quote(label));
+        }
+        if let Some(length) = node.length {
+            newick.push_str(&format!(":{}", length));
+        }
+    }
+    pub fn to_newick(&self) -> String {
+        let mut newick = String::new();
+        if !self.nodes.is_empty() {
+            self.write_newick(self.root, &mut newick);
+        }
+        newick.push(';');
+        newick
+    }
+}
+
+//--------------------------------- NEWICK PARSER ------------------------------------//
+
+struct NewickParser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl NewickParser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+    fn skip_whitespace(&mut self) -> Option<()> {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.position += 1;
+            } else if c == '[' {
+                while self.peek()? != ']' {
+                    self.position += 1;
+                }
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+        Some(())
+    }
+    fn expect(&mut self, expected: char) -> Option<()> {
+        self.skip_whitespace()?;
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+    fn parse_label(&mut self) -> Option<Option<String>> {
+        self.skip_whitespace()?;
+        let mut label = String::new();
+        if self.peek() == Some('\'') {
+            self.position += 1;
+            loop {
+                match self.peek()? {
+                    '\'' if self.chars.get(self.position + 1) == Some(&'\'') => {
+                        label.push('\'');
+                        self.position += 2;
+                    }
+                    '\'' => {
+                        self.position += 1;
+                        return Some(Some(label));
+                    }
+                    c => {
+                        label.push(c);
+                        self.position += 1;
+                    }
+                }
+            }
+        }
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || "()[]':;,".contains(c) {
+                break;
+            }
+            label.push(c);
+            self.position += 1;
+        }
+        if label.is_empty() {
+            Some(None)
+        } else {
+            Some(Some(label))
+        }
+    }
+    fn parse_length(&mut self) -> Option<Option<f64>> {
+        self.skip_whitespace()?;
+        if self.expect(':').is_none() {
+            return Some(None);
+        }
+        self.skip_whitespace()?;
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+        {
+            self.position += 1;
+        }
+        let number = self.chars[start..self.position].iter().collect::<String>();
+        number.parse().ok().map(Some)
+    }
+    fn parse_subtree(&mut self, tree: &mut Tree) -> Option<usize> {
+        let node = tree.add_node(None);
+        self.skip_whitespace()?;
+        if self.peek() == Some('(') {
+            self.position += 1;
+            loop {
+                let child = self.parse_subtree(tree)?;
+                tree.nodes[node].children.push(child);
+                tree.nodes[child].parent = Some(node);
+                self.skip_whitespace()?;
+                match self.peek()? {
+                    ',' => self.position += 1,
+                    ')' => {
+                        self.position += 1;
+                        break;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+        tree.nodes[node].label = self.parse_label()?;
+        tree.nodes[node].length = self.parse_length()?;
+        Some(node)
+    }
+}
+
+impl Tree {
+    pub fn from_newick(newick: &str) -> Option<Tree> {
+        let mut parser = NewickParser {
+            chars: newick.chars().collect(),
+            position: 0,
+        };
+        let mut tree = Tree::empty(true);
+        tree.root = parser.parse_subtree(&mut tree)?;
+        parser.expect(';')?;
+        parser.skip_whitespace()?;
+        if parser.peek().is_some() {
+            return None;
+        }
+        tree.rooted = tree.nodes[tree.root].children.len() <= 2;
+        Some(tree)
+    }
+}
+
+//---------------------------------- TREE QUERIES ------------------------------------//
+
+impl Tree {
+    pub fn find(&self, label: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|n| n.label.as_deref() == Some(label))
+    }
+    pub fn leaves(&self) -> Vec<&str> {
+        self.nodes
+            .iter()
+            .filter(|n| n.is_leaf())
+            .filter_map(|n| n.label.as_deref())
+            .collect()
+    }
+    fn ancestors(&self, id: usize) -> Vec<usize> {
+        let mut ancestors = vec![id];
+        while let Some(parent) = self.nodes[*ancestors.last().unwrap()].parent {
+            ancestors.push(parent);
+        }
+        ancestors
+    }
+    fn path(&self, first: &str, second: &str) -> Option<Vec<usize>> {
+        let mut first = self.ancestors(self.find(first)?);
+        let mut second = self.ancestors(self.find(second)?);
+        while first.len() > 1
+            && second.len() > 1
+            && first[first.len() - 2] == second[second.len() - 2]
+        {
+            first.pop();
+            second.pop();
+        }
+        first.pop();
+        second.pop();
+        first.append(&mut second);
+        Some(first)
+    }
+    pub fn path_length(&self, first: &str, second: &str) -> Option<usize> {
+        self.path(first, second).map(|path| path.len())
+    }
+    pub fn weighted_distance(&self, first: &str, second: &str) -> Option<f64> {
+        self.path(first, second)?
+            .iter()
+            .map(|id| self.nodes[*id].length)
+            .sum()
+    }
+}
+
+//----------------------------------- REROOTING --------------------------------------//
+
+fn add_lengths(first: Option<f64>, second: Option<f64>) -> Option<f64> {
+    match (first, second) {
+        (Some(a), Some(b)) => Some(a + b),
+        (Some(a), None) | (None, Some(a)) => Some(a),
+        (None, None) => None,
+    }
+}
+
+impl Tree {
+    fn neighbors(&self) -> Vec<Vec<(usize, Option<f64>)>> {
+        let mut neighbors = vec![Vec::new(); self.nodes.len()];
+        for (id, node) in self.nodes.iter().enumerate() {
+            if let Some(parent) = node.parent {
+                neighbors[id].insert(0, (parent, node.length));
+            }
+            for child in &node.children {
+                neighbors[id].push((*child, self.nodes[*child].length));
+            }
+        }
+        neighbors
+    }
+    fn copy_from(
+        &self,
+        tree: &mut Tree,
+        neighbors: &[Vec<(usize, Option<f64>)>],
+        old: usize,
+        from: Option<usize>,
+    ) -> usize {
+        let id = tree.add_node(self.nodes[old].label.clone());
+        for &(next, length) in &neighbors[old] {
+            if Some(next) == from {
+                continue;
+            }
+            let (mut next, mut length, mut previous) = (next, length, old);
+            let suppressed = |n: usize| {
+                n == self.root && neighbors[n].len() == 2 && self.nodes[n].label.is_none()
+            };
+            if suppressed(next) {
+                let (other, other_length) = neighbors[next]
+                    .iter()
+                    .copied()
+                    .find(|e| e.0 != previous)
+                    .unwrap();
+                length = add_lengths(length, other_length);
+                previous = next;
+                next = other;
+            }
+            let child = self.copy_from(tree, neighbors, next, Some(previous));
+            tree.attach(id, child, length);
+        }
+        id
+    }
+    pub fn reroot(&self, label: &str) -> Option<Tree> {
+        let target = self.find(label)?;
+        let neighbors = self.neighbors();
+        let mut tree = Tree::empty(self.rooted);
+        tree.root = self.copy_from(&mut tree, &neighbors, target, None);
+        if let [child] = tree.nodes[tree.root].children[..] {
+            let leaf = tree.root;
+            let length = tree.nodes[child].length;
+            tree.nodes[leaf].children.clear();
+            tree.root = tree.add_node(None);
+            tree.attach(tree.root, leaf, length.map(|_| 0.0));
+            tree.attach(tree.root, child, length);
+        }
+        Some(tree)
+    }
+}
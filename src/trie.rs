@@ -0,0 +1,161 @@
+use crate::monomers::Monomer;
+use crate::polymers::Polymer;
+use crate::polymers::PolymerSlice;
+use std::collections::BTreeMap;
+
+//-------------------------------------- TRIE ----------------------------------------//
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Trie<M: Monomer> {
+    root: TrieNode<M>,
+    len: usize,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct TrieNode<M: Monomer> {
+    children: BTreeMap<M, TrieNode<M>>,
+    terminal: bool,
+}
+
+impl<M: Monomer> TrieNode<M> {
+    fn new() -> Self {
+        TrieNode {
+            children: BTreeMap::new(),
+            terminal: false,
+        }
+    }
+    fn remove(&mut self, monomers: &[M]) -> bool {
+        match monomers.split_first() {
+            None => {
+                let removed = self.terminal;
+                self.terminal = false;
+                removed
+            }
+            Some((monomer, rest)) => {
+                let child = match self.children.get_mut(monomer) {
+                    Some(child) => child,
+                    None => return false,
+                };
+                let removed = child.remove(rest);
+                if !child.terminal && child.children.is_empty() {
+                    self.children.remove(monomer);
+                }
+                removed
+            }
+        }
+    }
+}
+
+impl<M: Monomer> Default for Trie<M> {
+    fn default() -> Self {
+        Trie::new()
+    }
+}
+
+impl<M: Monomer> Trie<M> {
+    pub fn new() -> Self {
+        Trie {
+            root: TrieNode::new(),
+            len: 0,
+        }
+    }
+    pub fn from_polymers(polymers: &[Polymer<M>]) -> Self {
+        let mut trie = Trie::new();
+        for polymer in polymers {
+            trie.insert(polymer);
+        }
+        trie
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn insert(&mut self, polymer: &PolymerSlice<M>) -> bool {
+        let mut node = &mut self.root;
+        for monomer in &polymer.monomers {
+            node = node.children.entry(*monomer).or_insert_with(TrieNode::new);
+        }
+        let inserted = !node.terminal;
+        node.terminal = true;
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+    pub fn remove(&mut self, polymer: &PolymerSlice<M>) -> bool {
+        let removed = self.root.remove(&polymer.monomers);
+        if removed {
+            self.len -= 1;
+        }
+        removed
+    }
+    pub fn contains(&self, polymer: &PolymerSlice<M>) -> bool {
+        let mut node = &self.root;
+        for monomer in &polymer.monomers {
+            match node.children.get(monomer) {
+                Some(child) => node = child,
+                None => return false,
+            }
+        }
+        node.terminal
+    }
+    pub fn longest_prefix(&self, polymer: &PolymerSlice<M>) -> Option<Polymer<M>> {
+        let mut node = &self.root;
+        let mut length = 0;
+        for (i, monomer) in polymer.monomers.iter().enumerate() {
+            match node.children.get(monomer) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if node.terminal {
+                length = i + 1;
+            }
+        }
+        if length == 0 {
+            None
+        } else {
+            let monomers = polymer.monomers[..length].to_vec();
+            Some(Polymer { monomers })
+        }
+    }
+    pub fn adjacency_list(&self) -> Vec<(usize, usize, M)> {
+        let mut edges = Vec::new();
+        let mut next = 1;
+        let mut stack = vec![(1, self.root.children.iter())];
+        while let Some((id, children)) = stack.last_mut() {
+            let parent = *id;
+            match children.next() {
+                Some((monomer, child)) => {
+                    next += 1;
+                    edges.push((parent, next, *monomer));
+                    stack.push((next, child.children.iter()));
+                }
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+        edges
+    }
+    pub fn to_edge_list(&self) -> String {
+        self.adjacency_list()
+            .iter()
+            .map(|(parent, child, monomer)| format!("{} {} {}\n", parent, child, monomer.symbol()))
+            .collect()
+    }
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph trie {\n");
+        for (parent, child, monomer) in self.adjacency_list() {
+            dot.push_str(&format!(
+                "    {} -> {} [label=\"{}\"];\n",
+                parent,
+                child,
+                monomer.symbol()
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
@@ -0,0 +1,63 @@
+use rosalind::assembly::Assembly;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::records::Record;
+
+#[test]
+fn greedy() {
+    let fasta = ">Rosalind_56
+ATTAGACCTG
+>Rosalind_57
+CCTGCCGGAA
+>Rosalind_58
+AGACCTGCCG
+>Rosalind_59
+GCCGGAATAC
+";
+    let records = Record::from_fasta(fasta, DnaNucleotide::new).unwrap();
+    let assembly = Assembly::greedy(&records).unwrap();
+    assert_eq!(assembly.contig.to_string(), "ATTAGACCTGCCGGAATAC");
+    let actual = assembly
+        .placements
+        .iter()
+        .map(|p| (p.id.as_str(), p.start, p.end))
+        .collect::<Vec<_>>();
+    let expected = [
+        ("Rosalind_56", 1, 10),
+        ("Rosalind_57", 7, 16),
+        ("Rosalind_58", 4, 13),
+        ("Rosalind_59", 10, 19),
+    ];
+    assert_eq!(actual, expected);
+    assert_eq!(Assembly::exact(&records), Some(assembly));
+    assert!(Assembly::<DnaNucleotide>::greedy(&[]).is_none());
+}
+
+#[test]
+fn contained_reads() {
+    let fasta = ">a\nACGTTG\n>b\nGTTG\n>c\nTTGCA\n>d\nACGTTG\n";
+    let records = Record::from_fasta(fasta, DnaNucleotide::new).unwrap();
+    let assembly = Assembly::greedy(&records).unwrap();
+    assert_eq!(assembly.contig.to_string(), "ACGTTGCA");
+    let actual = assembly
+        .placements
+        .iter()
+        .map(|p| (p.start, p.end))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, [(1, 6), (3, 6), (4, 8), (1, 6)]);
+}
+
+#[test]
+fn exact() {
+    let fasta = ">a\nCATGC\n>b\nCTAAGT\n>c\nGCTA\n>d\nTTCA\n>e\nATGCATC\n";
+    let records = Record::from_fasta(fasta, DnaNucleotide::new).unwrap();
+    let greedy = Assembly::greedy(&records).unwrap();
+    let exact = Assembly::exact(&records).unwrap();
+    assert!(exact.contig.len() <= greedy.contig.len());
+    for assembly in [greedy, exact] {
+        for (record, placement) in records.iter().zip(&assembly.placements) {
+            let read = &assembly.contig[placement.start - 1..placement.end];
+            assert_eq!(read, &*record.polymer);
+        }
+    }
+}
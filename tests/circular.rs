@@ -0,0 +1,94 @@
+use rosalind::circular::CircularPolymer;
+use rosalind::circular::CircularRna;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::monomers::RnaNucleotide;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn motif_locations() {
+    let plasmid = CircularPolymer::new("ATATGCGAT", DnaNucleotide::new).unwrap();
+    let actual = plasmid.motif_locations(&new_dna("GATAT"));
+    let expected = [7];
+    assert_eq!(actual, expected);
+    let actual = plasmid.motif_locations(&new_dna("AT"));
+    let expected = [1, 3, 8];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn kmers() {
+    let plasmid = CircularPolymer::new("ACGT", DnaNucleotide::new).unwrap();
+    let actual = plasmid.kmers(3);
+    let expected = [
+        new_dna("ACG"),
+        new_dna("CGT"),
+        new_dna("GTA"),
+        new_dna("TAC"),
+    ];
+    assert_eq!(actual, expected);
+    assert_eq!(plasmid.kmer_count(2).len(), 4);
+}
+
+#[test]
+fn rotation() {
+    let plasmid = CircularPolymer::new("GATTACA", DnaNucleotide::new).unwrap();
+    let rotated = plasmid.rotate(3);
+    assert_eq!(rotated.as_linear(), &new_dna("TACAGAT"));
+    assert_eq!(plasmid.rotate(10), rotated);
+    let canonical = plasmid.canonical_rotation();
+    assert_eq!(canonical.as_linear(), &new_dna("ACAGATT"));
+    assert!(rotated.is_rotation_of(&plasmid));
+    let other = CircularPolymer::new("GATTACT", DnaNucleotide::new).unwrap();
+    assert!(!other.is_rotation_of(&plasmid));
+}
+
+#[test]
+fn from_kmers() {
+    let kmers = [
+        new_dna("ATTAC"),
+        new_dna("TACAG"),
+        new_dna("GATTA"),
+        new_dna("ACAGA"),
+        new_dna("CAGAT"),
+        new_dna("TTACA"),
+        new_dna("AGATT"),
+    ];
+    let actual = CircularPolymer::from_kmers(&kmers).unwrap();
+    let expected = CircularPolymer::new("GATTACA", DnaNucleotide::new).unwrap();
+    assert!(actual.is_rotation_of(&expected));
+    let empty = Vec::new().into_iter().collect::<Dna>();
+    assert!(CircularPolymer::from_kmers(&[new_dna("ATTAC"), empty]).is_none());
+    let repeated = [kmers.as_slice(), &kmers[..2]].concat();
+    let actual = CircularPolymer::from_kmers(&repeated).unwrap();
+    assert!(actual.is_rotation_of(&expected));
+    let disjoint = ["AC", "CA", "GT", "TG"].map(new_dna);
+    assert!(CircularPolymer::from_kmers(&disjoint).is_none());
+    let branching = ["AC", "AG", "CA", "GA"].map(new_dna);
+    assert!(CircularPolymer::from_kmers(&branching).is_none());
+}
+
+#[test]
+fn reverse_complement() {
+    let plasmid = CircularPolymer::new("AAAACCCGGT", DnaNucleotide::new).unwrap();
+    let actual = plasmid.reverse_complement();
+    assert_eq!(actual.as_linear(), &new_dna("ACCGGGTTTT"));
+    assert!((plasmid.gc_content() - 50.0).abs() <= 0.00001);
+}
+
+#[test]
+fn translate() {
+    let plasmid: CircularRna = CircularPolymer::new("GCCUAAAUG", RnaNucleotide::new).unwrap();
+    let actual = plasmid
+        .translate()
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>();
+    let expected = ["MA"];
+    assert_eq!(actual, expected);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
@@ -0,0 +1,76 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::records::Record;
+
+#[test]
+fn correct_reads() {
+    let fasta = ">Rosalind_52
+TCATC
+>Rosalind_44
+TTCAT
+>Rosalind_68
+TCATC
+>Rosalind_28
+TGAAA
+>Rosalind_95
+GAGGA
+>Rosalind_66
+TTTCA
+>Rosalind_33
+ATCAA
+>Rosalind_21
+TTGAT
+>Rosalind_18
+TTTCC
+";
+    let records = Record::from_fasta(fasta, DnaNucleotide::new).unwrap();
+    let corrections = Record::correct_reads(&records);
+    let actual = corrections
+        .iter()
+        .map(|c| (c.id.as_str(), c.old.symbol(), c.new.symbol(), c.position))
+        .collect::<Vec<_>>();
+    let expected = [
+        ("Rosalind_44", 'C', 'G', 3),
+        ("Rosalind_95", 'G', 'T', 3),
+        ("Rosalind_18", 'C', 'A', 5),
+    ];
+    assert_eq!(actual, expected);
+    let actual = records
+        .iter()
+        .map(|r| r.corrected(&corrections).polymer.to_string())
+        .filter(|r| r.starts_with('G') || r.starts_with("TT"))
+        .collect::<Vec<String>>();
+    assert_eq!(actual, ["TTGAT", "GATGA", "TTTCA", "TTGAT", "TTTCA"]);
+}
+
+#[test]
+fn fastq() {
+    let fastq = "@r1\nACGTTGCA\n+\nIIIIIIII\n@r2\nGTTGCATC\n+\nIIIIIIII\n";
+    let records = Record::from_fastq(fastq, DnaNucleotide::new).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1].id, "r2");
+    assert_eq!(records[1].polymer.to_string(), "GTTGCATC");
+    assert!(Record::from_fastq("@r1\nACGT\n+\nIII\n", DnaNucleotide::new).is_none());
+    assert!(Record::from_fastq("@r1\nACGT\nIIII\n", DnaNucleotide::new).is_none());
+}
+
+#[test]
+fn kmer_spectrum() {
+    let fastq = "@r1\nATGGCGTACG\n+\nIIIIIIIIII\n\
+                 @r2\nGCGTACGTTA\n+\nIIIIIIIIII\n\
+                 @r3\nTAACGTACGC\n+\nIIIIIIIIII\n\
+                 @r4\nATGGCGTACG\n+\nIIIIIIIIII\n\
+                 @r5\nGCGTTCGTTA\n+\nIIIIIIIIII\n";
+    let records = Record::from_fastq(fastq, DnaNucleotide::new).unwrap();
+    let corrections = Record::correct_errors(&records, 5, 2);
+    let actual = corrections
+        .iter()
+        .map(|c| (c.id.as_str(), c.old.symbol(), c.new.symbol(), c.position))
+        .collect::<Vec<_>>();
+    assert_eq!(actual, [("r5", 'T', 'A', 5)]);
+    assert_eq!(
+        records[4].corrected(&corrections).polymer.to_string(),
+        "GCGTACGTTA"
+    );
+    assert!(Record::correct_errors(&records, 0, 2).is_empty());
+}
@@ -0,0 +1,64 @@
+use rosalind::circular::CircularPolymer;
+use rosalind::debruijn::DeBruijnGraph;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn reverse_complements() {
+    let reads = ["TGAT", "CATG", "TCAT", "ATGC", "CATC", "CATC"].map(new_dna);
+    let graph = DeBruijnGraph::with_reverse_complements(&reads, 4);
+    let actual = graph.to_edge_list();
+    let expected = "(ATC, TCA)\n(ATG, TGA)\n(ATG, TGC)\n(CAT, ATC)\n(CAT, ATG)\n(GAT, ATG)\n(GCA, CAT)\n(TCA, CAT)\n(TGA, GAT)\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn eulerian_path() {
+    let reads = ["ACGGT", "GGTCA"].map(new_dna);
+    let graph = DeBruijnGraph::new(&reads, 3);
+    assert_eq!(graph.nodes().len(), 6);
+    assert_eq!(graph.edge_count(), 5);
+    assert_eq!(graph.eulerian_path(), Some(new_dna("ACGGTCA")));
+    assert!(graph.eulerian_cycle().is_none());
+}
+
+#[test]
+fn eulerian_cycle() {
+    let reads = [
+        "ATTAC", "TACAG", "GATTA", "ACAGA", "CAGAT", "TTACA", "AGATT",
+    ]
+    .map(new_dna);
+    let graph = DeBruijnGraph::new(&reads, 5);
+    let actual = graph.eulerian_cycle().unwrap();
+    let expected = CircularPolymer::new("GATTACA", DnaNucleotide::new).unwrap();
+    assert!(actual.is_rotation_of(&expected));
+    assert_eq!(graph.eulerian_path().unwrap().len(), 11);
+}
+
+#[test]
+fn unitigs() {
+    let reads = ["AACGTA", "CGTC", "GTAGG"].map(new_dna);
+    let graph = DeBruijnGraph::new(&reads, 3);
+    let mut actual = graph.unitigs();
+    actual.sort();
+    let expected = ["AACGT", "GTAGG", "GTC"].map(new_dna);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn contigs() {
+    let reads = ["ACGGTCA"].map(new_dna);
+    let graph = DeBruijnGraph::with_reverse_complements(&reads, 4);
+    let actual = graph.contigs(5);
+    let expected = [new_dna("ACGGTCA")];
+    assert_eq!(actual, expected);
+    assert!(graph.contigs(8).is_empty());
+    let dot = graph.to_dot();
+    assert!(dot.contains("    \"ACG\" -> \"CGG\";\n"));
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
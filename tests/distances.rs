@@ -0,0 +1,86 @@
+use rosalind::distances::DistanceError;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::DnaSlice;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn distance_matrix() {
+    let dnas = vec![
+        new_dna("TTTCCATTTA"),
+        new_dna("GATTCATTTC"),
+        new_dna("TTTCCATTTT"),
+        new_dna("GTTCCATTTA"),
+    ];
+    let actual = Dna::distance_matrix(&dnas, DnaSlice::p_distance).unwrap();
+    let expected = [
+        [0.0, 0.4, 0.1, 0.1],
+        [0.4, 0.0, 0.4, 0.3],
+        [0.1, 0.4, 0.0, 0.2],
+        [0.1, 0.3, 0.2, 0.0],
+    ];
+    for (actual, expected) in actual.iter().flatten().zip(expected.iter().flatten()) {
+        assert!((actual - expected).abs() <= 0.00001);
+    }
+}
+
+#[test]
+fn corrected_distances() {
+    let dna = new_dna("ACGTACGTACGTACGTACGT");
+    let other = new_dna("ACATACGTTCGTACGAACGT");
+    let p: f64 = 0.15;
+    let jukes_cantor = -0.75 * (1.0 - 4.0 * p / 3.0).ln();
+    let (transitions, transversions): (f64, f64) = (0.05, 0.10);
+    let kimura = -0.5 * (1.0 - 2.0 * transitions - transversions).ln()
+        - 0.25 * (1.0 - 2.0 * transversions).ln();
+    assert!((dna.p_distance(&other).unwrap() - p).abs() <= 0.000001);
+    assert!((dna.jukes_cantor_distance(&other).unwrap() - jukes_cantor).abs() <= 0.000001);
+    assert!((dna.kimura_distance(&other).unwrap() - kimura).abs() <= 0.000001);
+    assert!(dna.tamura_distance(&other).unwrap() > p);
+    assert!(dna.logdet_distance(&other).unwrap() > p);
+}
+
+#[test]
+fn identical() {
+    let dna = new_dna("ACGTTGCA");
+    assert_eq!(dna.jukes_cantor_distance(&dna), Ok(0.0));
+    assert!(dna.kimura_distance(&dna).unwrap().abs() <= 0.000001);
+    assert!(dna.tamura_distance(&dna).unwrap().abs() <= 0.000001);
+    assert!(dna.logdet_distance(&dna).unwrap().abs() <= 0.000001);
+}
+
+#[test]
+fn errors() {
+    let dna = new_dna("ACGT");
+    assert_eq!(
+        dna.p_distance(&new_dna("ACG")),
+        Err(DistanceError::LengthMismatch)
+    );
+    assert_eq!(
+        dna.jukes_cantor_distance(&new_dna("CATG")),
+        Err(DistanceError::Saturated)
+    );
+    assert_eq!(
+        dna.kimura_distance(&new_dna("GTAC")),
+        Err(DistanceError::Saturated)
+    );
+    assert_eq!(
+        new_dna("AAAA").tamura_distance(&new_dna("TTTT")),
+        Err(DistanceError::Saturated)
+    );
+    assert_eq!(
+        new_dna("AACC").logdet_distance(&new_dna("AACC")),
+        Err(DistanceError::Saturated)
+    );
+    assert_eq!(dna[..0].p_distance(&dna[..0]), Err(DistanceError::Empty));
+    let dnas = vec![dna.clone(), new_dna("CATG")];
+    assert_eq!(
+        Dna::distance_matrix(&dnas, DnaSlice::jukes_cantor_distance),
+        Err(DistanceError::Saturated)
+    );
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
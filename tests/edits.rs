@@ -0,0 +1,85 @@
+use rosalind::edits::Edit;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn substitute() {
+    let mut dna = new_dna("GATTACA");
+    let edit = dna.substitute(3, DnaNucleotide::Cytosine).unwrap();
+    assert_eq!(dna, new_dna("GATCACA"));
+    assert!(edit.is_substitution());
+    assert_eq!(edit.position, 3);
+    assert_eq!(edit.removed, [DnaNucleotide::Thymine]);
+    assert_eq!(edit.inserted, [DnaNucleotide::Cytosine]);
+    assert!(dna.substitute(7, DnaNucleotide::Cytosine).is_none());
+    assert!(dna
+        .substitute(usize::MAX, DnaNucleotide::Cytosine)
+        .is_none());
+    let overflowing = Edit {
+        position: usize::MAX,
+        removed: vec![DnaNucleotide::Adenine],
+        inserted: vec![DnaNucleotide::Cytosine],
+    };
+    assert!(!dna.apply(&overflowing));
+    assert!(!dna.undo(&overflowing));
+    assert_eq!(dna, new_dna("GATCACA"));
+}
+
+#[test]
+fn insert_delete() {
+    let mut dna = new_dna("GATTACA");
+    let insertion = dna.insert(7, &new_dna("GG")).unwrap();
+    assert!(insertion.is_insertion());
+    assert_eq!(dna, new_dna("GATTACAGG"));
+    let deletion = dna.delete(1..3).unwrap();
+    assert!(deletion.is_deletion());
+    assert_eq!(
+        deletion.removed,
+        [DnaNucleotide::Adenine, DnaNucleotide::Thymine]
+    );
+    assert_eq!(dna, new_dna("GTACAGG"));
+    assert!(dna.delete(5..8).is_none());
+    assert!(dna.delete(0..7).is_none());
+    assert!(dna.replace(0..7, &new_dna("GG")[0..0]).is_none());
+    let emptying = Edit {
+        position: 0,
+        removed: dna.iter().copied().collect(),
+        inserted: Vec::new(),
+    };
+    assert!(!dna.apply(&emptying));
+    assert_eq!(dna, new_dna("GTACAGG"));
+}
+
+#[test]
+fn replace_undo() {
+    let mut dna = new_dna("GATTACA");
+    let original = dna.clone();
+    let edits = [
+        dna.replace(2..5, &new_dna("CCCCC")).unwrap(),
+        dna.concatenate(&new_dna("TT")),
+        dna.substitute(0, DnaNucleotide::Thymine).unwrap(),
+    ];
+    assert_eq!(dna, new_dna("TACCCCCCATT"));
+    for edit in edits.iter().rev() {
+        assert!(dna.undo(edit));
+    }
+    assert_eq!(dna, original);
+    assert!(!dna.undo(&edits[1]));
+    assert!(dna.apply(&edits[0]));
+    assert_eq!(dna, new_dna("GACCCCCCA"));
+}
+
+#[test]
+fn collect_extend() {
+    let dna = new_dna("GATTACA");
+    let mut actual = dna.iter().rev().copied().collect::<Dna>();
+    actual.extend(dna.iter().copied());
+    let expected = new_dna("ACATTAGGATTACA");
+    assert_eq!(actual, expected);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
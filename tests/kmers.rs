@@ -0,0 +1,118 @@
+use rosalind::kmers::kmer_rank;
+use rosalind::kmers::kmer_unrank;
+use rosalind::monomers::AminoAcid;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Protein;
+
+#[test]
+fn kmers() {
+    let dna = new_dna("ACGTA");
+    let actual = dna.kmers(3).map(|k| k.to_string()).collect::<Vec<_>>();
+    let expected = ["ACG", "CGT", "GTA"];
+    assert_eq!(actual, expected);
+    assert_eq!(dna.kmers(6).count(), 0);
+    assert_eq!(dna.kmers(0).count(), 0);
+}
+
+#[test]
+fn kmer_count() {
+    let dna = new_dna("AAAAT");
+    let actual = dna.kmer_count(2);
+    assert_eq!(actual.len(), 2);
+    assert_eq!(actual[&*new_dna("AA")], 3);
+    assert_eq!(actual[&*new_dna("AT")], 1);
+}
+
+#[test]
+fn kmer_composition() {
+    let dna = new_dna("CTTCGAAAGTTTGGGCCGAGTCTTACAGTCGGTCTTGAAGCAAAGTAACGAACTCCACGG");
+    let actual = dna.kmer_composition(2).unwrap();
+    let expected = vec![7, 4, 5, 0, 3, 2, 5, 4, 4, 2, 4, 5, 2, 5, 2, 5];
+    assert_eq!(actual, expected);
+    let protein = Polymer::new("MKWVTFISLLFLFSSAYS", AminoAcid::new).unwrap();
+    assert_eq!(protein.kmer_composition(2).unwrap().len(), 400);
+    assert!(protein.kmer_composition(8).is_none());
+    assert!(protein.kmer_composition(15).is_none());
+}
+
+#[test]
+fn rank() {
+    let dna = new_dna("GTA");
+    let rank = kmer_rank(&dna).unwrap();
+    assert_eq!(rank, 2 * 16 + 3 * 4);
+    assert_eq!(kmer_unrank::<DnaNucleotide>(rank, 3), dna);
+    let dna = new_dna(&"T".repeat(32));
+    assert_eq!(kmer_rank(&dna), Some(usize::MAX));
+    assert!(kmer_rank(&new_dna(&"T".repeat(40))).is_none());
+}
+
+#[test]
+fn all_kmers() {
+    let alphabet = [AminoAcid::Alanine, AminoAcid::Cysteine, AminoAcid::Glycine];
+    let actual = Protein::all_kmers(&alphabet, 2)
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>();
+    let expected = ["AA", "AC", "AG", "CA", "CC", "CG", "GA", "GC", "GG"];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn all_kmers_up_to() {
+    let alphabet = [
+        AminoAcid::AsparticAcid,
+        AminoAcid::Asparagine,
+        AminoAcid::Alanine,
+    ];
+    let actual = Protein::all_kmers_up_to(&alphabet, 3)
+        .iter()
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(actual.len(), 39);
+    assert_eq!(actual[..6], ["D", "DD", "DDD", "DDN", "DDA", "DN"]);
+    assert_eq!(actual[38], "AAA");
+}
+
+#[test]
+fn canonical_kmers() {
+    let dna = new_dna("AACGTT");
+    let actual = dna.canonical_kmers(3);
+    let expected = [
+        new_dna("AAC"),
+        new_dna("ACG"),
+        new_dna("ACG"),
+        new_dna("AAC"),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn encoded_kmers() {
+    let dna = new_dna("ACGTTGCATGTCGCATGATGCATGAGAGCT");
+    let k = 5;
+    for (kmer, encoded) in dna.kmers(k).zip(dna.encoded_kmers(k).unwrap()) {
+        assert_eq!(kmer_rank(kmer).unwrap() as u64, encoded.forward);
+        assert_eq!(
+            kmer_unrank::<DnaNucleotide>(encoded.reverse as usize, k),
+            kmer.reverse_complement()
+        );
+    }
+    assert_eq!(dna.encoded_kmers(k).unwrap().count(), 26);
+    assert!(dna.encoded_kmers(33).is_none());
+    assert!(new_dna("ACGT").encoded_kmers(32).unwrap().next().is_none());
+}
+
+#[test]
+fn canonical_kmer_count() {
+    let dna = new_dna("AACGTT");
+    let actual = dna.canonical_kmer_count(3).unwrap();
+    assert_eq!(actual.len(), 2);
+    assert_eq!(actual[&(kmer_rank(&new_dna("AAC")).unwrap() as u64)], 2);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
@@ -0,0 +1,60 @@
+use rosalind::masses::MassType;
+use rosalind::masses::Modification;
+use rosalind::monomers::AminoAcid;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Protein;
+
+#[test]
+fn peptide_mass() {
+    let protein = new_protein("SKADYEK");
+    let actual = protein.peptide_mass(MassType::Monoisotopic, &[]);
+    assert!((actual - 839.402485).abs() < 1e-6);
+    let actual = protein.peptide_mass(MassType::Average, &[]);
+    assert!((actual - 839.90058).abs() < 1e-6);
+    let actual = protein.protonated_mass(MassType::Monoisotopic, &[]);
+    assert!((actual - 840.409761).abs() < 1e-6);
+    let actual = protein
+        .mass_to_charge(2, MassType::Monoisotopic, &[])
+        .unwrap();
+    assert!((actual - 420.708518).abs() < 1e-6);
+    assert!(protein.mass_to_charge(0, MassType::Average, &[]).is_none());
+}
+
+#[test]
+fn modifications() {
+    let carbamidomethyl = Modification::find("carbamidomethyl").unwrap();
+    let oxidation = Modification::find("Oxidation").unwrap();
+    let phospho = Modification::find("PHOSPHO").unwrap();
+    assert!(Modification::find("Acetyl").is_none());
+    assert!(phospho.targets(&AminoAcid::Tyrosine));
+    assert!(!phospho.targets(&AminoAcid::Methionine));
+    let protein = new_protein("MCSK");
+    let actual = protein.peptide_mass(MassType::Monoisotopic, &[carbamidomethyl]);
+    assert!((actual - 524.208699).abs() < 1e-6);
+    let actual = protein.variable_masses(
+        MassType::Monoisotopic,
+        &[carbamidomethyl],
+        &[oxidation, phospho],
+        2,
+    );
+    let expected = [524.208699, 540.203614, 604.17503, 620.169945];
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-6);
+    }
+    let actual = protein.variable_masses(
+        MassType::Monoisotopic,
+        &[carbamidomethyl],
+        &[oxidation, phospho],
+        1,
+    );
+    assert_eq!(actual.len(), 3);
+    let protein = new_protein(&"MSTY".repeat(25));
+    let actual = protein.variable_masses(MassType::Monoisotopic, &[], &[oxidation, phospho], 5);
+    assert_eq!(actual.len(), 21);
+}
+
+fn new_protein(symbols: &str) -> Protein {
+    Polymer::new(symbols, AminoAcid::new).unwrap()
+}
@@ -0,0 +1,51 @@
+use rosalind::metrics::AssemblyReport;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn assembly_report() {
+    let contigs = ["GATTACA", "TACTACTAC", "ATTGAT", "GAAGA"].map(new_dna);
+    let report = AssemblyReport::new(&contigs, Some(40)).unwrap();
+    assert_eq!(report.contigs, 4);
+    assert_eq!(report.total_length, 27);
+    assert_eq!(report.largest_contig, 9);
+    assert_eq!((report.n50, report.n75), (7, 6));
+    assert_eq!(report.ng50, Some(6));
+    assert_eq!(report.l50, 2);
+    assert!((report.gc_content - 800.0 / 27.0).abs() < 1e-9);
+    assert_eq!((report.n_runs, report.n_bases), (0, 0));
+    assert!(AssemblyReport::new(&[], None).is_none());
+    let empty = Vec::new().into_iter().collect::<Dna>();
+    assert!(AssemblyReport::new(&[empty.clone(), empty], Some(10)).is_none());
+    assert!(AssemblyReport::from_scaffolds(&["NNN"], None).is_none());
+}
+
+#[test]
+fn scaffolds() {
+    let report =
+        AssemblyReport::from_scaffolds(&["GATTACANNNTACTACTAC", "NATTGATNGAAGA"], None).unwrap();
+    assert_eq!(report.contigs, 4);
+    assert_eq!((report.n50, report.n75), (7, 6));
+    assert_eq!(report.ng50, None);
+    assert_eq!((report.n_runs, report.n_bases), (3, 5));
+    assert!(AssemblyReport::from_scaffolds(&["GATUACA"], None).is_none());
+}
+
+#[test]
+fn render() {
+    let contigs = ["GATTACA", "TACTACTAC", "ATTGAT", "GAAGA"].map(new_dna);
+    let report = AssemblyReport::new(&contigs, None).unwrap();
+    let table = report.to_table();
+    assert!(table.starts_with("Contigs             4\n"));
+    assert!(table.contains("GC (%)          29.63\n"));
+    assert!(table.contains("NG50                -\n"));
+    let tsv = report.to_tsv();
+    assert!(tsv.starts_with("metric\tvalue\nContigs\t4\nTotal length\t27\n"));
+    assert!(tsv.contains("N50\t7\n"));
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
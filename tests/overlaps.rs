@@ -0,0 +1,50 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::overlaps::OverlapGraph;
+use rosalind::records::Record;
+
+const FASTA: &str = ">Rosalind_0498
+AAATAAA
+>Rosalind_2391
+AAATTTT
+>Rosalind_2323
+TTTTCCC
+>Rosalind_0442
+AAATCCC
+>Rosalind_5013
+GGGTGGG
+";
+
+#[test]
+fn fasta() {
+    let records = Record::from_fasta(FASTA, DnaNucleotide::new).unwrap();
+    assert_eq!(records.len(), 5);
+    assert_eq!(records[1].id, "Rosalind_2391");
+    assert_eq!(records[1].polymer.to_string(), "AAATTTT");
+    assert_eq!(Record::to_fasta(&records), FASTA);
+    assert!(Record::from_fasta("ACGT", DnaNucleotide::new).is_none());
+    assert!(Record::from_fasta(">a\nACGU", DnaNucleotide::new).is_none());
+}
+
+#[test]
+fn overlap_graph() {
+    let records = Record::from_fasta(FASTA, DnaNucleotide::new).unwrap();
+    let graph = OverlapGraph::new(&records, 3);
+    let actual = graph.to_edge_list();
+    let expected =
+        "Rosalind_0498 Rosalind_2391\nRosalind_0498 Rosalind_0442\nRosalind_2391 Rosalind_2323\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn min_overlap() {
+    let fasta = ">a\nACGTAC\n>b\nGTACCA\n>c\nTACCAG\n";
+    let records = Record::from_fasta(fasta, DnaNucleotide::new).unwrap();
+    let graph = OverlapGraph::with_min_overlap(&records, 3);
+    let actual = graph.adjacency_list();
+    let expected = [("a", "b", 4), ("a", "c", 3), ("b", "c", 5)];
+    assert_eq!(actual, expected);
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph overlaps {\n"));
+    assert!(dot.contains("    \"a\" -> \"b\" [label=\"4\"];\n"));
+}
@@ -0,0 +1,78 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::packed::PackedDna;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+const SEQUENCE: &str =
+    "CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGACTGGGAACCTGCGGGCAGTAGGTGGAAT";
+
+#[test]
+fn round_trip() {
+    let dna = new_dna(SEQUENCE);
+    let packed = PackedDna::from(&dna);
+    assert_eq!(packed.len(), SEQUENCE.len());
+    assert_eq!(packed.get(0), Some(DnaNucleotide::Cytosine));
+    assert_eq!(packed.get(SEQUENCE.len()), None);
+    assert_eq!(Dna::from(&packed), dna);
+}
+
+#[test]
+fn monomer_count() {
+    let dna = new_dna(SEQUENCE);
+    let actual = PackedDna::from(&dna).monomer_count();
+    let expected = dna.monomer_count();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn gc_content() {
+    let packed = PackedDna::from(&new_dna(SEQUENCE));
+    let actual = packed.gc_content();
+    let expected = 60.91954;
+    assert!((actual - expected).abs() <= 0.00001);
+    let dna = new_dna(SEQUENCE);
+    for length in [1, 31, 32, 33, 64, 65] {
+        let slice = &dna[..length];
+        let actual = PackedDna::from(slice).gc_content();
+        assert!((actual - slice.gc_content()).abs() <= 0.00001);
+    }
+}
+
+#[test]
+fn reverse_complement() {
+    let dna = new_dna(SEQUENCE);
+    let actual = Dna::from(&PackedDna::from(&dna).reverse_complement());
+    let expected = dna.reverse_complement();
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn hamming_distance() {
+    let dna = PackedDna::from(&new_dna("GAGCCTACTAACGGGAT"));
+    let actual = dna.hamming_distance(&PackedDna::from(&new_dna("CATCGTAATGACGGCCT")));
+    let expected = 7;
+    assert_eq!(actual, expected);
+    let dna = new_dna(SEQUENCE);
+    let other = dna.reverse_complement();
+    let actual = PackedDna::from(&dna).hamming_distance(&PackedDna::from(&other));
+    let expected = dna.hamming_distance(&other);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn motif_locations() {
+    let dna = PackedDna::from(&new_dna("GATATATGCATATACTT"));
+    let actual = dna.motif_locations(&PackedDna::from(&new_dna("ATAT")));
+    let expected = [2, 4, 10];
+    assert_eq!(actual, expected);
+    let dna = new_dna(&SEQUENCE.repeat(3));
+    let motif = new_dna(&SEQUENCE[10..50]);
+    let actual = PackedDna::from(&dna).motif_locations(&PackedDna::from(&motif));
+    let expected = dna.motif_locations(&motif);
+    assert_eq!(actual, expected);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
@@ -0,0 +1,79 @@
+use rosalind::monomers::AminoAcid;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::monomers::Nucleotide;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Protein;
+use rosalind::trees::Tree;
+
+use std::collections::HashMap;
+
+#[test]
+fn small_parsimony() {
+    let tree = Tree::from_newick("((a,b)x,(c,d)y)r;").unwrap();
+    let leaves = HashMap::from([
+        ("a".to_string(), new_dna("AC")),
+        ("b".to_string(), new_dna("AT")),
+        ("c".to_string(), new_dna("GC")),
+        ("d".to_string(), new_dna("GT")),
+    ]);
+    let parsimony = tree.small_parsimony(&leaves).unwrap();
+    assert_eq!(parsimony.score, 3);
+    let ancestor = |label| &parsimony.ancestors[&tree.find(label).unwrap()];
+    assert_eq!(ancestor("x"), &new_dna("AC"));
+    assert_eq!(ancestor("y"), &new_dna("GC"));
+    assert_eq!(ancestor("r"), &new_dna("AC"));
+    assert_eq!(parsimony.ancestors.len(), 3);
+}
+
+#[test]
+fn protein_parsimony() {
+    let tree = Tree::from_newick(
+        "(((ostrich,cat)rat,(duck,fly)mouse)dog,(elephant,pikachu)hamster)robot;",
+    )
+    .unwrap();
+    let leaves = HashMap::from([
+        ("ostrich".to_string(), new_protein("MKV")),
+        ("cat".to_string(), new_protein("MKL")),
+        ("duck".to_string(), new_protein("MRV")),
+        ("fly".to_string(), new_protein("MRV")),
+        ("elephant".to_string(), new_protein("AKV")),
+        ("pikachu".to_string(), new_protein("MKV")),
+    ]);
+    let parsimony = tree.small_parsimony(&leaves).unwrap();
+    assert_eq!(parsimony.score, 3);
+    let ancestor = |label| &parsimony.ancestors[&tree.find(label).unwrap()];
+    assert_eq!(ancestor("robot"), &new_protein("MKV"));
+    assert_eq!(ancestor("mouse"), &new_protein("MRV"));
+}
+
+#[test]
+fn weighted_parsimony() {
+    let tree = Tree::from_newick("(a,b)r;").unwrap();
+    let leaves = HashMap::from([
+        ("a".to_string(), new_dna("A")),
+        ("b".to_string(), new_dna("G")),
+    ]);
+    let transversion = |x: DnaNucleotide, y: DnaNucleotide| {
+        if x == y {
+            0
+        } else if x.is_purine() == y.is_purine() {
+            1
+        } else {
+            5
+        }
+    };
+    let parsimony = tree.weighted_parsimony(&leaves, transversion).unwrap();
+    assert_eq!(parsimony.score, 1);
+    let missing = HashMap::from([("a".to_string(), new_dna("A"))]);
+    assert!(tree.small_parsimony(&missing).is_none());
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
+
+fn new_protein(symbols: &str) -> Protein {
+    Polymer::new(symbols, AminoAcid::new).unwrap()
+}
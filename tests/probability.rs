@@ -0,0 +1,52 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+
+#[test]
+fn log_probability() {
+    let dna = new_dna("ACGATACAA");
+    let actual =
+        [0.129, 0.287, 0.423, 0.476, 0.641, 0.742, 0.783].map(|gc| dna.log_probability(gc));
+    let expected = [-5.737, -5.217, -5.263, -5.360, -5.958, -6.628, -7.009];
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!((actual - expected).abs() <= 0.001);
+    }
+}
+
+#[test]
+fn expected_occurrences() {
+    let dna = new_dna("AG");
+    let actual = [0.25, 0.5, 0.75].map(|gc| dna.expected_occurrences(10, gc));
+    let expected = [0.422, 0.563, 0.422];
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!((actual - expected).abs() <= 0.001);
+    }
+    let actual = dna.log_expected_occurrences(10, 0.5);
+    assert!((actual - 0.563_f64.log10()).abs() <= 0.001);
+}
+
+#[test]
+fn log_probability_any_match() {
+    let dna = new_dna("ATAGCCGA");
+    let actual = dna.log_probability_any_match(90000, 0.6);
+    let expected = 0.689_f64.log10();
+    assert!((actual - expected).abs() <= 0.001);
+}
+
+#[test]
+fn long_sequence() {
+    let dna = new_dna(&"ACGT".repeat(500));
+    let log_probability = dna.log_probability(0.5);
+    assert!((log_probability - 2000.0 * 0.25_f64.log10()).abs() <= 0.000001);
+    let actual = dna.log_probability_any_match(1000, 0.5);
+    assert!((actual - (3.0 + log_probability)).abs() <= 0.000001);
+    let actual = dna.log_expected_occurrences(3000, 0.5);
+    assert!(actual.is_finite());
+    assert!((actual - (1001_f64.log10() + log_probability)).abs() <= 0.000001);
+    assert_eq!(dna.log_expected_occurrences(1000, 0.5), f64::NEG_INFINITY);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
@@ -0,0 +1,61 @@
+use rosalind::monomers::AminoAcid;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Protein;
+use rosalind::proteases::Cleavage;
+use rosalind::proteases::Protease;
+
+#[test]
+fn proteases() {
+    let trypsin = Protease::find("trypsin").unwrap();
+    assert_eq!(trypsin.residues, "KR");
+    assert_eq!(trypsin.cleavage, Cleavage::CTerminal);
+    assert!(trypsin.proline_exception);
+    assert!(Protease::find("Pepsin").is_none());
+    let protein = new_protein("AKPRTEKGFYPDK");
+    assert_eq!(protein.cleavage_sites(&[trypsin]), [4, 7]);
+    let lys_c = Protease::find("Lys-C").unwrap();
+    assert_eq!(protein.cleavage_sites(&[lys_c]), [2, 7]);
+    let chymotrypsin = Protease::find("Chymotrypsin").unwrap();
+    assert_eq!(protein.cleavage_sites(&[chymotrypsin]), [9]);
+    let asp_n = Protease::find("Asp-N").unwrap();
+    assert_eq!(protein.cleavage_sites(&[asp_n]), [11]);
+    let glu_c = Protease::find("Glu-C").unwrap();
+    assert_eq!(protein.cleavage_sites(&[glu_c, asp_n]), [6, 11]);
+    let custom = Protease::new("Custom", "GP", Cleavage::NTerminal, false);
+    assert_eq!(protein.cleavage_sites(&[custom]), [2, 7, 10]);
+}
+
+#[test]
+fn digest() {
+    let protein = new_protein("AKPRTEKGFYPDK");
+    let trypsin = Protease::find("Trypsin").unwrap();
+    let peptides = protein.digest(&[trypsin], 0, 1..=usize::MAX);
+    let actual = peptides
+        .iter()
+        .map(|p| (p.protein.to_string(), p.start, p.end))
+        .collect::<Vec<_>>();
+    let expected = [
+        ("AKPR".to_string(), 1, 4),
+        ("TEK".to_string(), 5, 7),
+        ("GFYPDK".to_string(), 8, 13),
+    ];
+    assert_eq!(actual, expected);
+    assert!((peptides[1].mass - 376.195795).abs() < 1e-6);
+    let peptides = protein.digest(&[trypsin], 1, 4..=7);
+    let actual = peptides
+        .iter()
+        .map(|p| (p.protein.to_string(), p.missed_cleavages))
+        .collect::<Vec<_>>();
+    let expected = [
+        ("AKPR".to_string(), 0),
+        ("AKPRTEK".to_string(), 1),
+        ("GFYPDK".to_string(), 0),
+    ];
+    assert_eq!(actual, expected);
+    assert!(protein.digest(&[trypsin], 2, 14..=20).is_empty());
+}
+
+fn new_protein(symbols: &str) -> Protein {
+    Polymer::new(symbols, AminoAcid::new).unwrap()
+}
@@ -0,0 +1,64 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Protein;
+use rosalind::polymers::Rna;
+use rosalind::random::Rng;
+use rosalind::random::SubstitutionModel;
+
+use std::collections::HashMap;
+
+#[test]
+fn deterministic() {
+    let first = Dna::random(200, &mut Rng::new(42));
+    let second = Dna::random(200, &mut Rng::new(42));
+    let third = Dna::random(200, &mut Rng::new(43));
+    assert_eq!(first, second);
+    assert_ne!(first, third);
+    assert_eq!(first.len(), 200);
+    assert_eq!(Protein::random(50, &mut Rng::new(7)).len(), 50);
+    assert_eq!(Rna::random(50, &mut Rng::new(7)).len(), 50);
+}
+
+#[test]
+fn composition() {
+    let composition = HashMap::from([(DnaNucleotide::Adenine, 3.0), (DnaNucleotide::Thymine, 1.0)]);
+    let dna = Dna::random_with_composition(10000, &composition, &mut Rng::new(1)).unwrap();
+    let count = dna.monomer_count();
+    assert_eq!(count.len(), 2);
+    let adenine = count[&DnaNucleotide::Adenine] as f64 / 10000.0;
+    assert!((adenine - 0.75).abs() <= 0.02);
+    assert!(Dna::random_with_composition(10, &HashMap::new(), &mut Rng::new(1)).is_none());
+}
+
+#[test]
+fn gc_content() {
+    let dna = Dna::random_with_gc(10000, 0.7, &mut Rng::new(5)).unwrap();
+    assert!((dna.gc_content() - 70.0).abs() <= 2.0);
+    assert!(Dna::random_with_gc(10, 1.5, &mut Rng::new(5)).is_none());
+}
+
+#[test]
+fn jukes_cantor() {
+    let mut rng = Rng::new(11);
+    let dna = Dna::random(10000, &mut rng);
+    let mutated = dna.mutate(0.1, SubstitutionModel::JukesCantor, &mut rng);
+    let distance = dna.hamming_distance(&mutated) as f64 / 10000.0;
+    assert!((distance - 0.1).abs() <= 0.01);
+    assert!((dna.transition_transversion_ratio(&mutated) - 0.5).abs() <= 0.1);
+    let again = dna.mutate(0.1, SubstitutionModel::JukesCantor, &mut Rng::new(11));
+    assert_eq!(
+        again,
+        dna.mutate(0.1, SubstitutionModel::JukesCantor, &mut Rng::new(11))
+    );
+}
+
+#[test]
+fn kimura() {
+    let mut rng = Rng::new(13);
+    let dna = Dna::random(20000, &mut rng);
+    let model = SubstitutionModel::Kimura {
+        transition_transversion_ratio: 2.0,
+    };
+    let mutated = dna.mutate(0.2, model, &mut rng);
+    assert!((dna.transition_transversion_ratio(&mutated) - 2.0).abs() <= 0.2);
+}
@@ -0,0 +1,76 @@
+use rosalind::circular::CircularPolymer;
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::restriction::Enzyme;
+use rosalind::restriction::Overhang;
+
+#[test]
+fn reverse_palindromes() {
+    let dna = new_dna("TCAATGCATGCGGGTCTATATGCAT");
+    let actual = dna.reverse_palindromes(4, 12);
+    let expected = [
+        (4, 6),
+        (5, 4),
+        (6, 6),
+        (7, 4),
+        (17, 4),
+        (18, 4),
+        (20, 6),
+        (21, 4),
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn enzymes() {
+    let ecori = Enzyme::find("ecori").unwrap();
+    assert_eq!(ecori.recognition_site(), Some(new_dna("GAATTC")));
+    assert_eq!(ecori.overhang(), Overhang::FivePrime);
+    assert_eq!(Enzyme::find("SmaI").unwrap().overhang(), Overhang::Blunt);
+    assert_eq!(
+        Enzyme::find("PstI").unwrap().overhang(),
+        Overhang::ThreePrime
+    );
+    assert!(Enzyme::find("Unknown").is_none());
+}
+
+#[test]
+fn linear_digest() {
+    let dna = new_dna("AAGAATTCAAAAGGATCCAA");
+    let ecori = Enzyme::find("EcoRI").unwrap();
+    let bamhi = Enzyme::find("BamHI").unwrap();
+    let actual = dna.digest(&[ecori]);
+    assert_eq!(actual.len(), 2);
+    assert_eq!((actual[0].start, actual[0].end), (1, 3));
+    assert_eq!(actual[1].dna, new_dna("AATTCAAAAGGATCCAA"));
+    let actual = dna.digest(&[ecori, bamhi]);
+    let coordinates = actual.iter().map(|f| (f.start, f.end)).collect::<Vec<_>>();
+    assert_eq!(coordinates, [(1, 3), (4, 13), (14, 20)]);
+    assert_eq!(actual[1].dna, new_dna("AATTCAAAAG"));
+    assert_eq!(dna.digest(&[]).len(), 1);
+    let actual = dna[8..20].digest(&[ecori, bamhi]);
+    let coordinates = actual.iter().map(|f| (f.start, f.end)).collect::<Vec<_>>();
+    assert_eq!(coordinates, [(1, 5), (6, 12)]);
+    assert_eq!(actual[1].dna, new_dna("GATCCAA"));
+}
+
+#[test]
+fn circular_digest() {
+    let plasmid = CircularPolymer::new("ATTCAAAAGGATCCAAGA", DnaNucleotide::new).unwrap();
+    let ecori = Enzyme::find("EcoRI").unwrap();
+    let bamhi = Enzyme::find("BamHI").unwrap();
+    let actual = plasmid.digest(&[ecori]);
+    assert_eq!(actual.len(), 1);
+    assert_eq!((actual[0].start, actual[0].end), (18, 17));
+    assert_eq!(actual[0].dna, new_dna("AATTCAAAAGGATCCAAG"));
+    let actual = plasmid.digest(&[ecori, bamhi]);
+    let coordinates = actual.iter().map(|f| (f.start, f.end)).collect::<Vec<_>>();
+    assert_eq!(coordinates, [(10, 17), (18, 9)]);
+    assert_eq!(actual[1].dna, new_dna("AATTCAAAAG"));
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
@@ -0,0 +1,93 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::sketches::MinHash;
+use rosalind::sketches::Syncmer;
+
+const SEQUENCE: &str = "ATGCGTACGTTAGCCGATCGATCGGCTAAGCTTAGCGCGATATCGACGTAGCTAGCTGACTGATCGTACG";
+
+#[test]
+fn minimizers() {
+    let dna = new_dna(SEQUENCE);
+    let (w, k) = (4, 5);
+    let seeds = dna.minimizers(1, k).unwrap();
+    let mut expected = Vec::new();
+    for window in seeds.windows(w) {
+        let minimizer = *window.iter().min_by_key(|s| s.hash).unwrap();
+        if expected.last() != Some(&minimizer) {
+            expected.push(minimizer);
+        }
+    }
+    let actual = dna.minimizers(w, k).unwrap();
+    assert_eq!(actual, expected);
+    assert_eq!(seeds.len(), SEQUENCE.len() - k + 1);
+    assert!(dna.minimizers(0, k).is_none());
+}
+
+#[test]
+fn syncmers() {
+    let dna = new_dna(SEQUENCE);
+    let (k, s) = (7, 3);
+    let smers = dna.minimizers(1, s).unwrap();
+    let kmers = dna.minimizers(1, k).unwrap();
+    let offsets = kmers
+        .iter()
+        .map(|kmer| {
+            let window = &smers[kmer.position - 1..kmer.position - 1 + k - s + 1];
+            window
+                .iter()
+                .enumerate()
+                .min_by_key(|e| e.1.hash)
+                .unwrap()
+                .0
+        })
+        .collect::<Vec<_>>();
+    let open = dna.syncmers(k, s, Syncmer::Open).unwrap();
+    let closed = dna.syncmers(k, s, Syncmer::Closed).unwrap();
+    let expected_open = kmers
+        .iter()
+        .zip(&offsets)
+        .filter(|e| *e.1 == 0)
+        .map(|e| *e.0)
+        .collect::<Vec<_>>();
+    let expected_closed = kmers
+        .iter()
+        .zip(&offsets)
+        .filter(|e| *e.1 == 0 || *e.1 == k - s)
+        .map(|e| *e.0)
+        .collect::<Vec<_>>();
+    assert_eq!(open, expected_open);
+    assert_eq!(closed, expected_closed);
+    assert!(dna.syncmers(3, 4, Syncmer::Open).is_none());
+}
+
+#[test]
+fn minhash() {
+    let dna = new_dna(SEQUENCE);
+    let sketch = MinHash::new(&dna, 9, 16).unwrap();
+    assert_eq!(sketch.hashes().len(), 16);
+    assert!(sketch.hashes().windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(sketch.jaccard(&sketch), 1.0);
+    assert_eq!(sketch.mash_distance(&sketch), 0.0);
+    let reverse = dna.reverse_complement();
+    assert_eq!(dna.jaccard(&reverse, 9, 16), Some(1.0));
+    let other = new_dna("CCCCCCCCCCCCCCCCCCCCCCCCCCCC");
+    assert_eq!(dna.jaccard(&other, 9, 16), Some(0.0));
+    assert_eq!(dna.mash_distance(&other, 9, 16), Some(1.0));
+}
+
+#[test]
+fn mash_distance() {
+    let dna = new_dna(SEQUENCE);
+    let mutated = new_dna(&SEQUENCE.replacen("GATAT", "GAGAT", 1));
+    let jaccard = dna.jaccard(&mutated, 5, 1000).unwrap();
+    let distance = dna.mash_distance(&mutated, 5, 1000).unwrap();
+    let expected = -(2.0 * jaccard / (1.0 + jaccard)).ln() / 5.0;
+    assert!(jaccard > 0.0 && jaccard < 1.0);
+    assert!((distance - expected).abs() <= 0.000001);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
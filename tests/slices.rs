@@ -0,0 +1,67 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::monomers::RnaNucleotide;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Rna;
+
+#[test]
+fn len_get_iter() {
+    let dna = new_dna("GATTACA");
+    let slice = &dna[1..4];
+    assert_eq!(slice.len(), 3);
+    assert_eq!(slice.get(0), Some(&DnaNucleotide::Adenine));
+    assert_eq!(slice.get(3), None);
+    assert_eq!(slice.iter().count(), 3);
+    assert_eq!(slice.to_string(), "ATT");
+    assert!(dna[3..3].is_empty());
+}
+
+#[test]
+fn split_at() {
+    let dna = new_dna("GATTACA");
+    let (left, right) = dna.split_at(3);
+    assert_eq!(left.to_polymer(), new_dna("GAT"));
+    assert_eq!(right.to_owned(), new_dna("TACA"));
+    assert_eq!(&right[..2], &*new_dna("TA"));
+}
+
+#[test]
+fn gc_content() {
+    let dna = new_dna("AAAAGGCCAAAA");
+    let actual = dna[4..8].gc_content();
+    let expected = 100.0;
+    assert!((actual - expected).abs() <= 0.00001);
+}
+
+#[test]
+fn motif_locations() {
+    let dna = new_dna("TTTGATATATGCATATACTT");
+    let actual = dna[3..].motif_locations(&new_dna("ATAT"));
+    let expected = [2, 4, 10];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn reverse_complement() {
+    let dna = new_dna("GGAAAACCCGGTGG");
+    let actual = dna[2..12].reverse_complement();
+    let expected = new_dna("ACCGGGTTTT");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn translate() {
+    let rna = new_rna("CCAUGGCCUAA");
+    let actual = rna[2..].translate();
+    assert_eq!(actual.len(), 1);
+    assert_eq!(actual[0].to_string(), "MA");
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
+
+fn new_rna(symbols: &str) -> Rna {
+    Polymer::new(symbols, RnaNucleotide::new).unwrap()
+}
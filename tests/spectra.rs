@@ -0,0 +1,123 @@
+use rosalind::monomers::AminoAcid;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Polymer;
+use rosalind::polymers::Protein;
+use rosalind::spectra::spectral_convolution;
+use rosalind::spectra::SpectrumGraph;
+
+#[test]
+fn prefix_spectrum() {
+    let spectrum = [3524.8542, 3710.9335, 3841.974, 3970.0326, 4057.0646];
+    let candidates = Protein::from_prefix_spectrum(&spectrum, 0.01).unwrap();
+    let actual = candidates.proteins().collect::<Vec<Protein>>();
+    let expected = [new_protein("WMQS")];
+    assert_eq!(actual, expected);
+    assert_eq!(candidates.len(), 4);
+    assert!(Protein::from_prefix_spectrum(&[3524.8542, 3600.0], 0.01).is_none());
+    assert!(Protein::from_prefix_spectrum(&[3524.8542], 0.01).is_none());
+}
+
+#[test]
+fn isobaric_residues() {
+    let spectrum = [100.0, 213.08406, 341.17902];
+    let candidates = Protein::from_prefix_spectrum(&spectrum, 0.01).unwrap();
+    let actual = candidates.proteins().collect::<Vec<Protein>>();
+    let expected = ["IK", "LK"].map(new_protein);
+    assert_eq!(actual, expected);
+    let candidates = Protein::from_prefix_spectrum(&spectrum, 0.05).unwrap();
+    let actual = candidates.proteins().collect::<Vec<Protein>>();
+    let expected = ["IK", "IQ", "LK", "LQ"].map(new_protein);
+    assert_eq!(actual, expected);
+    assert_eq!(candidates.count(), Some(4));
+    assert!(candidates.contains(&new_protein("LQ")));
+    assert!(!candidates.contains(&new_protein("LA")));
+    let spectrum = (0..=64).map(|i| i as f64 * 113.08406).collect::<Vec<f64>>();
+    let candidates = Protein::from_prefix_spectrum(&spectrum, 0.01).unwrap();
+    assert_eq!(candidates.alternatives().len(), 64);
+    assert_eq!(candidates.count(), None);
+    assert_eq!(
+        candidates.proteins().nth(1).unwrap().to_string().pop(),
+        Some('L')
+    );
+}
+
+#[test]
+fn ion_spectrum() {
+    let ions = [
+        610.391039105,
+        738.485999105,
+        766.492149105,
+        863.544909105,
+        867.528589105,
+        992.587499105,
+        995.623549105,
+        1120.6824591,
+        1124.6661391,
+        1221.7188991,
+        1249.7250491,
+        1377.8200091,
+    ];
+    let actual = Protein::from_ion_spectrum(1988.21104821, &ions, 0.01)
+        .iter()
+        .flat_map(|c| c.proteins())
+        .collect::<Vec<Protein>>();
+    let expected = ["EKEPR", "KEKEP", "PEKEK", "RPEKE"].map(new_protein);
+    assert_eq!(actual, expected);
+    assert!(Protein::from_ion_spectrum(1988.21104821, &ions[..3], 0.01).is_empty());
+}
+
+#[test]
+fn spectrum_graph() {
+    let spectrum = [
+        3524.8542, 3623.5245, 3710.9335, 3841.974, 3929.00603, 3970.0326, 4026.05879, 4057.0646,
+        4083.08025,
+    ];
+    let graph = SpectrumGraph::new(&spectrum, 0.01);
+    assert_eq!(graph.masses().len(), 9);
+    let edges = graph.adjacency_list();
+    assert_eq!(edges.len(), 8);
+    assert_eq!(edges[0], (3524.8542, 3710.9335, AminoAcid::Tryptophan));
+    let candidates = graph.longest_proteins();
+    assert_eq!(candidates.len(), 1);
+    let actual = candidates[0].proteins().collect::<Vec<Protein>>();
+    let expected = [new_protein("WMSPG")];
+    assert_eq!(actual, expected);
+    assert!(SpectrumGraph::new(&[1.0, 2.0], 0.01)
+        .longest_proteins()
+        .is_empty());
+}
+
+#[test]
+fn convolution() {
+    let first = [
+        186.07931, 287.12699, 548.20532, 580.18077, 681.22845, 706.27446, 782.27613, 968.35544,
+        968.35544,
+    ];
+    let second = [
+        101.04768, 158.06914, 202.09536, 318.09979, 419.14747, 463.17369,
+    ];
+    let actual = spectral_convolution(&first, &second, 0.0001);
+    assert_eq!(actual.len(), 40);
+    assert_eq!(actual[0].1, 3);
+    assert!((actual[0].0 - 85.03163).abs() < 1e-4);
+    assert!(spectral_convolution(&first, &[], 0.0001).is_empty());
+}
+
+#[test]
+fn protein_matching() {
+    let protein = new_protein("GSDMQS");
+    assert_eq!(protein.complete_spectrum().len(), 10);
+    assert!((protein.complete_spectrum()[9] - 548.19007).abs() < 1e-4);
+    let spectrum = [
+        58.02146, 88.03203, 145.05349, 216.09061, 260.08043, 347.1311, 391.12092, 300.0, 410.5,
+    ];
+    assert_eq!(protein.spectrum_score(&spectrum, 0.001), 7);
+    let candidates = ["VWICN", "GSDMQS", "IASKNMD", "SQMDSG"].map(new_protein);
+    let (score, matches) = Protein::best_matches(&candidates, &spectrum, 0.001);
+    assert_eq!(score, 7);
+    assert_eq!(matches, [&candidates[1], &candidates[3]]);
+}
+
+fn new_protein(symbols: &str) -> Protein {
+    Polymer::new(symbols, AminoAcid::new).unwrap()
+}
@@ -0,0 +1,55 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::trees::Tree;
+
+#[test]
+fn character_table() {
+    let dnas = vec![
+        new_dna("ATGCTACC"),
+        new_dna("CGTTTACC"),
+        new_dna("ATTCGACC"),
+        new_dna("AGTCTCCC"),
+        new_dna("CGTCTATC"),
+    ];
+    let actual = Dna::character_table(&dnas);
+    let expected = vec![
+        vec![true, false, true, true, false],
+        vec![true, false, true, false, false],
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn splits() {
+    let tree = Tree::from_newick("(dog,((elephant,mouse),robot),cat);").unwrap();
+    assert_eq!(tree.taxa(), ["cat", "dog", "elephant", "mouse", "robot"]);
+    let mut actual = tree.splits();
+    actual.sort();
+    let expected = vec![
+        vec![false, false, true, true, false],
+        vec![false, false, true, true, true],
+    ];
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn rooted_splits() {
+    let tree = Tree::from_newick("((a,b),(c,d));").unwrap();
+    assert_eq!(tree.splits().len(), 1);
+}
+
+#[test]
+fn split_distance() {
+    let tree = Tree::from_newick("(rat,(dog,cat),(rabbit,(elephant,mouse)));").unwrap();
+    let other = Tree::from_newick("(rat,(cat,dog),(elephant,(mouse,rabbit)));").unwrap();
+    assert_eq!(tree.split_distance(&other), Some(2));
+    assert_eq!(tree.split_distance(&tree), Some(0));
+    let different = Tree::from_newick("(rat,dog,cat);").unwrap();
+    assert_eq!(tree.split_distance(&different), None);
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}
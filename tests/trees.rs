@@ -0,0 +1,104 @@
+use rosalind::trees::Tree;
+
+#[test]
+fn upgma() {
+    let labels = ["a", "b", "c", "d", "e"];
+    let distances = vec![
+        vec![0.0, 17.0, 21.0, 31.0, 23.0],
+        vec![17.0, 0.0, 30.0, 34.0, 21.0],
+        vec![21.0, 30.0, 0.0, 28.0, 39.0],
+        vec![31.0, 34.0, 28.0, 0.0, 43.0],
+        vec![23.0, 21.0, 39.0, 43.0, 0.0],
+    ];
+    let tree = Tree::upgma(&labels, &distances).unwrap();
+    let actual = tree.to_newick();
+    let expected = "((e:11,(a:8.5,b:8.5):2.5):5.5,(c:14,d:14):2.5);";
+    assert_eq!(actual, expected);
+    assert!(tree.is_rooted());
+    assert_eq!(tree.len(), 9);
+}
+
+#[test]
+fn neighbor_joining() {
+    let labels = ["a", "b", "c", "d", "e"];
+    let distances = vec![
+        vec![0.0, 5.0, 9.0, 9.0, 8.0],
+        vec![5.0, 0.0, 10.0, 10.0, 9.0],
+        vec![9.0, 10.0, 0.0, 8.0, 7.0],
+        vec![9.0, 10.0, 8.0, 0.0, 3.0],
+        vec![8.0, 9.0, 7.0, 3.0, 0.0],
+    ];
+    let tree = Tree::neighbor_joining(&labels, &distances).unwrap();
+    let actual = tree.to_newick();
+    let expected = "(d:2,e:1,(c:4,(a:2,b:3):3):2);";
+    assert_eq!(actual, expected);
+    assert!(!tree.is_rooted());
+}
+
+#[test]
+fn small_inputs() {
+    let tree = Tree::neighbor_joining(&["a", "b"], &[vec![0.0, 4.0], vec![4.0, 0.0]]).unwrap();
+    assert_eq!(tree.to_newick(), "(a:2,b:2);");
+    assert_eq!(tree.taxa(), ["a", "b"]);
+    let tree = Tree::upgma(&["a b"], &[vec![0.0]]).unwrap();
+    assert_eq!(tree.to_newick(), "'a b';");
+    assert!(Tree::upgma(&["a", "b"], &[vec![0.0]]).is_none());
+    assert!(Tree::neighbor_joining(&[], &[]).is_none());
+}
+
+#[test]
+fn path_length() {
+    let tree = Tree::from_newick("(cat)dog;").unwrap();
+    assert_eq!(tree.path_length("dog", "cat"), Some(1));
+    let tree = Tree::from_newick("(dog,cat);").unwrap();
+    assert_eq!(tree.path_length("dog", "cat"), Some(2));
+    assert_eq!(tree.path_length("dog", "dog"), Some(0));
+    assert_eq!(tree.path_length("dog", "robot"), None);
+}
+
+#[test]
+fn weighted_distance() {
+    let tree = Tree::from_newick("(dog:42,cat:33);").unwrap();
+    assert_eq!(tree.weighted_distance("cat", "dog"), Some(75.0));
+    let tree = Tree::from_newick("((dog:4,cat:3):74,robot:98,elephant:58);").unwrap();
+    assert_eq!(tree.weighted_distance("dog", "elephant"), Some(136.0));
+    let tree = Tree::from_newick("((dog,cat:3):74,robot:98);").unwrap();
+    assert_eq!(tree.weighted_distance("dog", "robot"), None);
+}
+
+#[test]
+fn parse_newick() {
+    let newick = " ( 'Mus musculus':0.1 , 'O''Brien' [comment] :2.5e-1,(C,D)inner:1) root ; ";
+    let tree = Tree::from_newick(newick).unwrap();
+    assert_eq!(tree.leaves(), ["Mus musculus", "O'Brien", "C", "D"]);
+    assert_eq!(tree.node(tree.root()).unwrap().label(), Some("root"));
+    assert_eq!(
+        tree.weighted_distance("Mus musculus", "O'Brien"),
+        Some(0.35)
+    );
+    assert_eq!(tree.path_length("C", "inner"), Some(1));
+    let expected = "('Mus musculus':0.1,'O''Brien':0.25,(C,D)inner:1)root;";
+    assert_eq!(tree.to_newick(), expected);
+    assert!(!tree.is_rooted());
+    assert!(Tree::from_newick("(a,b;").is_none());
+    assert!(Tree::from_newick("(a,b);c").is_none());
+    assert!(Tree::from_newick("(a:x,b);").is_none());
+    assert!(Tree::from_newick("(a:[").is_none());
+    assert!(Tree::from_newick("(a,b)[comment;").is_none());
+}
+
+#[test]
+fn reroot() {
+    let tree = Tree::from_newick("((a:1,b:2):3,(c:4,d:5):6);").unwrap();
+    let rerooted = tree.reroot("a").unwrap();
+    assert_eq!(rerooted.to_newick(), "(a:0,((c:4,d:5):9,b:2):1);");
+    for (first, second) in [("a", "b"), ("a", "d"), ("b", "c"), ("c", "d")] {
+        assert_eq!(
+            tree.weighted_distance(first, second),
+            rerooted.weighted_distance(first, second)
+        );
+    }
+    assert_eq!(rerooted.taxa(), tree.taxa());
+    assert_eq!(tree.split_distance(&rerooted), Some(0));
+    assert!(tree.reroot("e").is_none());
+}
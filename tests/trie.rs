@@ -0,0 +1,53 @@
+use rosalind::monomers::DnaNucleotide;
+use rosalind::monomers::Monomer;
+use rosalind::polymers::Dna;
+use rosalind::polymers::Polymer;
+use rosalind::trie::Trie;
+
+#[test]
+fn edge_list() {
+    let trie = Trie::from_polymers(&[new_dna("ATAGA"), new_dna("ATC"), new_dna("GAT")]);
+    let actual = trie.to_edge_list();
+    let expected = "1 2 A\n2 3 T\n3 4 A\n4 5 G\n5 6 A\n3 7 C\n1 8 G\n8 9 A\n9 10 T\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn dot() {
+    let trie = Trie::from_polymers(&[new_dna("AT"), new_dna("G")]);
+    let actual = trie.to_dot();
+    let expected = "digraph trie {\n    1 -> 2 [label=\"A\"];\n    2 -> 3 [label=\"T\"];\n    1 -> 4 [label=\"G\"];\n}\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn longest_prefix() {
+    let trie = Trie::from_polymers(&[new_dna("AC"), new_dna("ACGT"), new_dna("T")]);
+    assert_eq!(
+        trie.longest_prefix(&new_dna("ACGTTA")),
+        Some(new_dna("ACGT"))
+    );
+    assert_eq!(trie.longest_prefix(&new_dna("ACGA")), Some(new_dna("AC")));
+    assert_eq!(trie.longest_prefix(&new_dna("GA")), None);
+}
+
+#[test]
+fn insert_remove() {
+    let mut trie = Trie::new();
+    assert!(trie.insert(&new_dna("ACGT")));
+    assert!(trie.insert(&new_dna("AC")));
+    assert!(!trie.insert(&new_dna("AC")));
+    assert_eq!(trie.len(), 2);
+    assert!(trie.remove(&new_dna("ACGT")));
+    assert!(!trie.remove(&new_dna("ACG")));
+    assert!(!trie.contains(&new_dna("ACGT")));
+    assert!(trie.contains(&new_dna("AC")));
+    assert_eq!(trie.adjacency_list().len(), 2);
+    assert!(trie.remove(&new_dna("AC")));
+    assert!(trie.is_empty());
+    assert!(trie.adjacency_list().is_empty());
+}
+
+fn new_dna(symbols: &str) -> Dna {
+    Polymer::new(symbols, DnaNucleotide::new).unwrap()
+}